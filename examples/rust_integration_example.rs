@@ -86,11 +86,40 @@ fn enhanced_docker(py: Python, m: &PyModule) -> PyResult<()> {
     Ok(())
 }
 
-/// Example: Custom configuration wrapper
+/// Generate a unique per-session label value, e.g. `a1b2c3d4-5e6f-...`. Not a
+/// spec-compliant UUIDv4 (this example has no `uuid` crate dependency to
+/// pull in), just unique enough to tag one `with ManagedDocker(...)` block's
+/// resources apart from any other's.
+fn generate_session_id() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let pid = std::process::id() as u64;
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("{pid:08x}-{nanos:016x}-{seq:08x}")
+}
+
+/// Example: session-scoped wrapper, modeled on the label-based cleanup
+/// approach pre-commit's Docker hooks use to avoid stomping on unrelated
+/// containers when running on a shared host.
+///
+/// Every container created through [`Self::create_container`] during the
+/// `with` block is tagged `docker_pyo3.session=<session_id>`; networks and
+/// volumes are not created or labeled by this wrapper. `__exit__` (when
+/// `auto_cleanup`) only stops+removes containers carrying *this* session's
+/// label - never every container on the host. For the
+/// all-or-nothing behavior, call the separate, explicitly-named
+/// `enhanced_docker::cleanup_all()` instead.
 #[pyclass]
 struct ManagedDocker {
     docker: Py<PyAny>,
     auto_cleanup: bool,
+    session_id: String,
 }
 
 #[pymethods]
@@ -104,33 +133,94 @@ impl ManagedDocker {
                 Some(uri) => docker_class.call1((uri,))?,
                 None => docker_class.call0()?,
             };
-            
+
             Ok(ManagedDocker {
                 docker: docker.into(),
                 auto_cleanup: auto_cleanup.unwrap_or(true),
+                session_id: generate_session_id(),
             })
         })
     }
-    
+
     fn get_docker(&self) -> Py<PyAny> {
         self.docker.clone()
     }
-    
-    fn __enter__(&mut self) -> PyResult<Py<PyAny>> {
-        Ok(self.docker.clone())
+
+    /// The `docker_pyo3.session=<value>` label this instance tags its
+    /// resources with.
+    fn session_label(&self) -> String {
+        format!("docker_pyo3.session={}", self.session_id)
     }
-    
+
+    /// Create a container through the managed `docker` handle, injecting
+    /// this session's label alongside any caller-supplied labels so it's
+    /// picked up by [`Self::cleanup_session`].
+    fn create_container(
+        &self,
+        py: Python<'_>,
+        image: String,
+        command: Option<Vec<String>>,
+        labels: Option<std::collections::HashMap<String, String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let mut labels = labels.unwrap_or_default();
+        labels.insert("docker_pyo3.session".to_string(), self.session_id.clone());
+
+        let containers = self.docker.call_method0(py, "containers")?;
+        let kwargs = [("image", image.into_py(py)), ("command", command.into_py(py)), ("labels", labels.into_py(py))]
+            .into_py_dict(py);
+        containers.call_method(py, "create", (), Some(kwargs)).map(Into::into)
+    }
+
+    /// List only the containers carrying this session's label.
+    fn list_session_containers(&self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
+        let containers = self.docker.call_method0(py, "containers")?;
+        let kwargs = [("all", true)].into_py_dict(py);
+        let summaries = containers.call_method(py, "list", (), Some(kwargs))?;
+        let mut session_containers = Vec::new();
+        for summary in summaries.bind(py).iter()? {
+            let summary = summary?;
+            let session = summary
+                .get_item("Labels")
+                .and_then(|labels| labels.get_item("docker_pyo3.session"))
+                .and_then(|value| value.extract::<String>());
+            if session.ok().as_deref() == Some(self.session_id.as_str()) {
+                session_containers.push(summary.unbind());
+            }
+        }
+        Ok(session_containers)
+    }
+
+    /// Stop and remove only the containers this `ManagedDocker` created -
+    /// i.e. those labeled with its session ID - leaving every other
+    /// container on the host untouched. Safe to call explicitly in addition
+    /// to the automatic `__exit__` cleanup.
+    fn cleanup_session(&self, py: Python<'_>) -> PyResult<()> {
+        let containers = self.docker.call_method0(py, "containers")?;
+        for summary in self.list_session_containers(py)? {
+            let id: String = summary.bind(py).get_item("Id")?.extract()?;
+            let container = containers.call_method1(py, "get", (id,))?;
+            container.call_method0(py, "stop").ok();
+            container.call_method0(py, "delete").ok();
+        }
+        Ok(())
+    }
+
+    /// Returns the `ManagedDocker` instance itself (not the raw `docker`
+    /// handle), so `create_container`/`cleanup_session` stay reachable from
+    /// the `with ... as managed:` binding.
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
     fn __exit__(
         &mut self,
+        py: Python<'_>,
         _exc_type: Option<&PyAny>,
         _exc_val: Option<&PyAny>,
         _exc_tb: Option<&PyAny>,
     ) -> PyResult<bool> {
         if self.auto_cleanup {
-            Python::with_gil(|py| {
-                // Cleanup logic here
-                println!("Auto-cleaning up Docker resources...");
-            });
+            self.cleanup_session(py)?;
         }
         Ok(false) // Don't suppress exceptions
     }
@@ -168,11 +258,14 @@ from enhanced_docker import Docker, quick_run, cleanup_all
 output = quick_run("alpine", ["echo", "Hello from Docker!"])
 cleanup_all()
 
-# Enterprise version with context manager
+# Enterprise version with context manager - only THIS block's containers
+# (labeled docker_pyo3.session=<id>) get stopped/removed on exit, not every
+# container on the host.
 from enterprise_docker import ManagedDocker
-with ManagedDocker(auto_cleanup=True) as docker:
-    containers = docker.containers()
+with ManagedDocker(auto_cleanup=True) as managed:
+    managed.create_container("alpine", ["sleep", "5"])
     # ... do work ...
-# Auto cleanup happens here
+# managed.cleanup_session() runs automatically here; call it directly too
+# if you want to reclaim resources mid-session without leaving the block.
 
 */
\ No newline at end of file
@@ -0,0 +1,407 @@
+use std::collections::{HashMap, HashSet};
+
+use docker_api::models::ContainerSummary;
+use docker_api::opts::{ContainerFilter, ContainerListOpts};
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::container::Pyo3Container;
+use crate::error::DockerPyo3Error;
+use crate::{get_runtime, Pyo3Docker};
+
+#[pymodule]
+pub fn compose(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Pyo3Compose>()?;
+    Ok(())
+}
+
+/// Labels stamped on every container `Compose.up()` creates, so `down()`
+/// can find what it owns - even from a fresh `Compose` instance - without
+/// keeping its own in-memory bookkeeping.
+const COMPOSE_PROJECT_LABEL: &str = "docker_pyo3.compose.project";
+const COMPOSE_SERVICE_LABEL: &str = "docker_pyo3.compose.service";
+
+/// A standalone, minimal translation of a `docker-compose.yml` into
+/// `Containers.create()` calls. Unlike `stack::Pyo3Stack` this has no
+/// convergence, health gating, or scaling: `up()` always creates and
+/// starts, `down()` always stops and removes whatever it labeled.
+#[pyclass(name = "Compose")]
+pub struct Pyo3Compose {
+    docker: Pyo3Docker,
+    project: String,
+    services: IndexMap<String, docker_compose_types::Service>,
+    declared_networks: Vec<String>,
+}
+
+#[pymethods]
+impl Pyo3Compose {
+    /// Parse a compose file's YAML content into service definitions.
+    ///
+    /// Args:
+    ///     docker: Docker client
+    ///     project: Project name, used to label/namespace created objects
+    ///     yaml: Raw `docker-compose.yml` content
+    ///
+    /// Returns:
+    ///     Compose: parsed definition, not yet applied to the daemon
+    #[staticmethod]
+    fn from_yaml(docker: Pyo3Docker, project: &str, yaml: &str) -> PyResult<Self> {
+        let compose: docker_compose_types::Compose =
+            serde_yaml::from_str(yaml).map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+
+        let services = compose
+            .services
+            .0
+            .into_iter()
+            .filter_map(|(name, service)| service.map(|s| (name, s)))
+            .collect();
+
+        let declared_networks = match compose.networks {
+            Some(networks) => networks.into_keys().collect(),
+            None => Vec::new(),
+        };
+
+        Ok(Pyo3Compose {
+            docker,
+            project: project.to_string(),
+            services,
+            declared_networks,
+        })
+    }
+
+    /// Service names in the order `up()` creates their containers:
+    /// services with no unmet `depends_on` first.
+    ///
+    /// Raises:
+    ///     ConfigurationError: if the services have a circular `depends_on`
+    fn deploy_order(&self) -> PyResult<Vec<String>> {
+        deploy_order(&self.services)
+    }
+
+    /// Create the declared networks (plus a project-scoped default one),
+    /// then create and start each service's container in dependency order.
+    ///
+    /// Returns:
+    ///     dict: `{"networks": [str, ...], "containers": {service: container_id, ...}}`
+    fn up(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let mut network_names = vec!["default".to_string()];
+        network_names.extend(self.declared_networks.iter().cloned());
+
+        let mut qualified_networks = Vec::with_capacity(network_names.len());
+        for network_name in &network_names {
+            let qualified = format!("{}_{network_name}", self.project);
+            self.docker.networks().get_or_create(
+                py, &qualified, None, None, None, None, None, None, None, None,
+            )?;
+            qualified_networks.push(qualified);
+        }
+
+        let mut containers = HashMap::new();
+        for service_name in self.deploy_order()? {
+            let config = &self.services[&service_name];
+            let container = self.create_service_container(py, &service_name, config)?;
+            container.start(py)?;
+            containers.insert(service_name, container.id());
+        }
+
+        let result = PyDict::new(py);
+        result.set_item("networks", qualified_networks)?;
+        result.set_item("containers", containers)?;
+        Ok(result.into())
+    }
+
+    /// Stop and remove every container labeled with this project, in
+    /// reverse dependency order.
+    ///
+    /// Returns:
+    ///     dict: `{"removed": [str, ...]}`
+    fn down(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let mut order = self.deploy_order()?;
+        order.reverse();
+
+        let mut removed = Vec::new();
+        for service_name in order {
+            for container_id in self.find_service_containers(py, &service_name)? {
+                let container = self.docker.containers().get(&container_id);
+                let _ = container.stop(py, None);
+                container.delete(py)?;
+                removed.push(container_id);
+            }
+        }
+
+        let result = PyDict::new(py);
+        result.set_item("removed", removed)?;
+        Ok(result.into())
+    }
+}
+
+impl Pyo3Compose {
+    /// Containers this project has already created for `service_name`,
+    /// found by label rather than in-memory state.
+    fn find_service_containers(&self, py: Python<'_>, service_name: &str) -> PyResult<Vec<String>> {
+        let containers = self.docker.containers();
+        let opts = ContainerListOpts::builder()
+            .all(true)
+            .filter(vec![
+                ContainerFilter::LabelKV(format!("{COMPOSE_PROJECT_LABEL}={}", self.project)),
+                ContainerFilter::LabelKV(format!("{COMPOSE_SERVICE_LABEL}={service_name}")),
+            ])
+            .build();
+
+        let summaries: Vec<ContainerSummary> = py
+            .allow_threads(|| get_runtime().block_on(containers.0.list(&opts)))
+            .map_err(DockerPyo3Error::from)?;
+
+        Ok(summaries.into_iter().filter_map(|c| c.id).collect())
+    }
+
+    /// Translate one compose service into a `Containers.create()` call,
+    /// labeled so `down()` (and `find_service_containers`) can find it.
+    fn create_service_container(
+        &self,
+        py: Python<'_>,
+        service_name: &str,
+        config: &docker_compose_types::Service,
+    ) -> PyResult<Pyo3Container> {
+        let image = config.image.as_deref().ok_or_else(|| {
+            DockerPyo3Error::Configuration(format!("service '{service_name}' has no image"))
+        })?;
+
+        let command = match &config.command {
+            Some(docker_compose_types::Command::Simple(cmd)) => {
+                Some(cmd.split_whitespace().map(String::from).collect::<Vec<_>>())
+            }
+            Some(docker_compose_types::Command::Args(args)) => Some(args.clone()),
+            None => None,
+        };
+
+        let env: Vec<String> = match &config.environment {
+            docker_compose_types::Environment::List(list) => list.clone(),
+            docker_compose_types::Environment::KvPair(map) => map
+                .iter()
+                .map(|(key, value)| {
+                    let value = value.as_ref().map(ToString::to_string).unwrap_or_default();
+                    format!("{key}={value}")
+                })
+                .collect(),
+        };
+
+        let mut labels: HashMap<String, String> = match &config.labels {
+            docker_compose_types::Labels::List(list) => list
+                .iter()
+                .filter_map(|label| label.split_once('=').map(|(k, v)| (k.to_string(), v.to_string())))
+                .collect(),
+            docker_compose_types::Labels::Map(map) => map.clone().into_iter().collect(),
+        };
+        labels.insert(COMPOSE_PROJECT_LABEL.to_string(), self.project.clone());
+        labels.insert(COMPOSE_SERVICE_LABEL.to_string(), service_name.to_string());
+
+        let volumes: Vec<String> = config
+            .volumes
+            .iter()
+            .map(|volume| match volume {
+                docker_compose_types::Volumes::Simple(spec) => spec.clone(),
+                docker_compose_types::Volumes::Advanced(advanced) => {
+                    format!("{}:{}", advanced.source.clone().unwrap_or_default(), advanced.target)
+                }
+            })
+            .collect();
+
+        let (expose, publish) = translate_ports(&config.ports)?;
+
+        let network_name = match &config.networks {
+            Some(docker_compose_types::Networks::Simple(names)) => names.first().cloned(),
+            Some(docker_compose_types::Networks::Advanced(names)) => names.keys().next().cloned(),
+            None => None,
+        }
+        .unwrap_or_else(|| "default".to_string());
+        let network_mode = format!("{}_{network_name}", self.project);
+
+        let container_name = format!("{}_{service_name}", self.project);
+
+        let command_list = command.map(|c| PyList::new(py, c));
+        let env_list = (!env.is_empty()).then(|| PyList::new(py, &env));
+        let volumes_list = (!volumes.is_empty()).then(|| PyList::new(py, &volumes));
+
+        let labels_dict = PyDict::new(py);
+        for (key, value) in &labels {
+            labels_dict.set_item(key, value)?;
+        }
+
+        let restart_policy_dict = config.restart.as_deref().map(|name| -> PyResult<_> {
+            let dict = PyDict::new(py);
+            dict.set_item("name", name)?;
+            Ok(dict)
+        }).transpose()?;
+
+        let expose_list = if expose.is_empty() {
+            None
+        } else {
+            let list = PyList::empty(py);
+            for (container_port, host_port, protocol) in &expose {
+                let dict = PyDict::new(py);
+                dict.set_item("srcport", container_port)?;
+                dict.set_item("hostport", host_port)?;
+                dict.set_item("protocol", protocol)?;
+                list.append(dict)?;
+            }
+            Some(list)
+        };
+
+        let publish_list = if publish.is_empty() {
+            None
+        } else {
+            let list = PyList::empty(py);
+            for (container_port, protocol) in &publish {
+                let dict = PyDict::new(py);
+                dict.set_item("port", container_port)?;
+                dict.set_item("protocol", protocol)?;
+                list.append(dict)?;
+            }
+            Some(list)
+        };
+
+        self.docker.containers().create(
+            py,
+            image,
+            None, // attach_stderr
+            None, // attach_stdin
+            None, // attach_stdout
+            None, // auto_remove
+            None, // blkio_weight
+            None, // capabilities
+            None, // cap_drop
+            command_list.as_ref(),
+            None, // cpu_period
+            None, // cpu_quota
+            None, // cpu_shares
+            None, // cpus
+            None, // devices
+            None, // dns
+            None, // dns_search
+            None, // entrypoint
+            env_list.as_ref(),
+            expose_list.as_ref(),
+            None, // extra_hosts
+            Some(&labels_dict),
+            None, // links
+            None, // log_driver
+            None, // memory
+            None, // memory_swap
+            Some(&container_name),
+            None, // nano_cpus
+            Some(network_mode.as_str()),
+            None, // oom_kill_disable
+            None, // pids_limit
+            None, // privileged
+            publish_list.as_ref(),
+            None, // publish_all_ports
+            None, // readonly_rootfs
+            restart_policy_dict.as_ref(),
+            None, // security_options
+            None, // shm_size
+            None, // stop_signal
+            None, // stop_signal_num
+            None, // stop_timeout
+            None, // tty
+            None, // user
+            None, // userns_mode
+            volumes_list.as_ref(),
+            None, // volumes_from
+            config.working_dir.as_deref(),
+            None, // resolve_host_paths
+        )
+    }
+}
+
+/// Topologically order `services` by `depends_on` (Kahn's algorithm, ties
+/// broken alphabetically for determinism).
+fn deploy_order(services: &IndexMap<String, docker_compose_types::Service>) -> PyResult<Vec<String>> {
+    let mut dependencies: HashMap<&str, Vec<String>> = HashMap::new();
+    for (name, config) in services {
+        let deps = match &config.depends_on {
+            docker_compose_types::DependsOnOptions::Simple(deps) => deps.clone(),
+            docker_compose_types::DependsOnOptions::Conditional(deps) => deps.keys().cloned().collect(),
+        };
+        dependencies.insert(name.as_str(), deps);
+    }
+
+    let mut remaining: HashSet<&str> = services.keys().map(String::as_str).collect();
+    let mut order: Vec<String> = Vec::new();
+
+    while !remaining.is_empty() {
+        let mut ready: Vec<&str> = remaining
+            .iter()
+            .copied()
+            .filter(|name| {
+                dependencies[name]
+                    .iter()
+                    .all(|dep| order.iter().any(|done| done == dep) || !remaining.contains(dep.as_str()))
+            })
+            .collect();
+
+        if ready.is_empty() {
+            return Err(DockerPyo3Error::Configuration(
+                "circular depends_on relationship among compose services".to_string(),
+            )
+            .into());
+        }
+
+        ready.sort_unstable();
+        for name in ready {
+            order.push(name.to_string());
+            remaining.remove(name);
+        }
+    }
+
+    Ok(order)
+}
+
+/// Translate compose `ports:` short/long syntax into the `(container_port,
+/// host_port, protocol)` / `(container_port, protocol)` shapes the
+/// `expose`/`publish` dicts `Containers.create()` already accepts expect.
+#[allow(clippy::type_complexity)]
+fn translate_ports(ports: &docker_compose_types::Ports) -> PyResult<(Vec<(u32, u32, String)>, Vec<(u32, String)>)> {
+    let mut expose = Vec::new();
+    let mut publish = Vec::new();
+
+    let short_specs: Vec<String> = match ports {
+        docker_compose_types::Ports::Short(specs) => specs.clone(),
+        docker_compose_types::Ports::Long(entries) => entries
+            .iter()
+            .map(|entry| match &entry.published {
+                Some(docker_compose_types::PublishedPort::Single(port)) => format!("{port}:{}", entry.target),
+                Some(docker_compose_types::PublishedPort::Range(range)) => format!("{range}:{}", entry.target),
+                None => entry.target.to_string(),
+            })
+            .collect(),
+    };
+
+    for spec in short_specs {
+        let (spec, protocol) = match spec.split_once('/') {
+            Some((rest, proto)) => (rest.to_string(), proto.to_string()),
+            None => (spec, "tcp".to_string()),
+        };
+
+        match spec.split_once(':') {
+            Some((host_port, container_port)) => {
+                let host_port: u32 = host_port
+                    .parse()
+                    .map_err(|_| DockerPyo3Error::Configuration(format!("invalid host port in '{spec}'")))?;
+                let container_port: u32 = container_port
+                    .parse()
+                    .map_err(|_| DockerPyo3Error::Configuration(format!("invalid container port in '{spec}'")))?;
+                expose.push((container_port, host_port, protocol));
+            }
+            None => {
+                let container_port: u32 = spec
+                    .parse()
+                    .map_err(|_| DockerPyo3Error::Configuration(format!("invalid port in '{spec}'")))?;
+                publish.push((container_port, protocol));
+            }
+        }
+    }
+
+    Ok((expose, publish))
+}
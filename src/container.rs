@@ -1,46 +1,51 @@
 use chrono::{DateTime, Utc};
 use docker_api::conn::TtyChunk;
 use docker_api::models::{
-    ContainerInspect200Response, ContainerPrune200Response, ContainerSummary, ContainerWaitResponse,
+    ContainerInspect200Response, ContainerPrune200Response, ContainerSummary, ContainerTopResponse,
 };
 use docker_api::opts::{
-    ContainerCreateOpts, ContainerListOpts, ContainerPruneOpts, ExecCreateOpts, LogsOpts,
-    PublishPort,
+    ContainerAttachOpts, ContainerCommitOpts, ContainerCreateOpts, ContainerListOpts,
+    ContainerPruneOpts, ExecCreateOpts, LogsOpts, PublishPort,
 };
-use docker_api::{Container, Containers};
-use futures_util::stream::StreamExt;
-use futures_util::TryStreamExt;
+use docker_api::{Container, Containers, Docker, Exec, Images};
+use futures_util::stream::{BoxStream, StreamExt};
+use futures_util::{SinkExt, TryStreamExt};
 use pyo3::exceptions;
 use pyo3::prelude::*;
-use pyo3::types::{PyDateTime, PyDelta, PyDict, PyList};
-use pythonize::pythonize;
+use pyo3::types::{PyBytes, PyDateTime, PyDelta, PyDict, PyList};
+use pythonize::{depythonize, pythonize};
 use std::{collections::HashMap, fs::File, io::Read};
 use tar::Archive;
 
-use crate::Pyo3Docker;
+use crate::error::DockerPyo3Error;
+use crate::{events, get_runtime, Pyo3Docker};
 
 #[pymodule]
 pub fn container(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Pyo3Containers>()?;
     m.add_class::<Pyo3Container>()?;
+    m.add_class::<Pyo3ContainerStats>()?;
+    m.add_class::<Pyo3Exec>()?;
+    m.add_class::<Pyo3LogStream>()?;
+    m.add_class::<Pyo3Attach>()?;
     Ok(())
 }
 
 /// Interface for managing Docker containers collection.
 #[derive(Debug)]
 #[pyclass(name = "Containers")]
-pub struct Pyo3Containers(pub Containers);
+pub struct Pyo3Containers(pub Containers, Docker);
 
 /// Represents an individual Docker container.
 #[derive(Debug)]
 #[pyclass(name = "Container")]
-pub struct Pyo3Container(pub Container);
+pub struct Pyo3Container(pub Container, Docker);
 
 #[pymethods]
 impl Pyo3Containers {
     #[new]
     pub fn new(docker: Pyo3Docker) -> Self {
-        Pyo3Containers(Containers::new(docker.0))
+        Pyo3Containers(Containers::new(docker.0.clone()), docker.0)
     }
 
     /// Get a specific container by ID or name.
@@ -50,8 +55,8 @@ impl Pyo3Containers {
     ///
     /// Returns:
     ///     Container: Container instance
-    fn get(&self, id: &str) -> Pyo3Container {
-        Pyo3Container(self.0.get(id))
+    pub fn get(&self, id: &str) -> Pyo3Container {
+        Pyo3Container(self.0.get(id), self.1.clone())
     }
 
     /// List containers.
@@ -67,6 +72,7 @@ impl Pyo3Containers {
     #[pyo3(signature = (all=None, since=None, before=None, sized=None))]
     fn list(
         &self,
+        py: Python<'_>,
         all: Option<bool>,
         since: Option<String>,
         before: Option<String>,
@@ -79,7 +85,7 @@ impl Pyo3Containers {
         bo_setter!(before, builder);
         bo_setter!(sized, builder);
 
-        let cs = __containers_list(&self.0, &builder.build());
+        let cs = py.allow_threads(|| __containers_list(&self.0, &builder.build()));
         pythonize_this!(cs)
     }
 
@@ -87,8 +93,8 @@ impl Pyo3Containers {
     ///
     /// Returns:
     ///     dict: Prune results including containers deleted and space reclaimed
-    fn prune(&self) -> PyResult<Py<PyAny>> {
-        let rv = __containers_prune(&self.0, &Default::default());
+    fn prune(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __containers_prune(&self.0, &Default::default()));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -104,11 +110,17 @@ impl Pyo3Containers {
     ///     attach_stdin: Attach to stdin
     ///     attach_stdout: Attach to stdout
     ///     auto_remove: Automatically remove the container when it exits
+    ///     blkio_weight: Block IO weight, a value between 10 and 1000
     ///     capabilities: List of Linux capabilities to add (e.g., ["NET_ADMIN", "SYS_TIME"])
+    ///     cap_drop: List of Linux capabilities to drop (e.g., ["MKNOD"])
     ///     command: Command to run as list (e.g., ["/bin/sh", "-c", "echo hello"])
+    ///     cpu_period: CPU CFS scheduler period in microseconds
+    ///     cpu_quota: CPU CFS scheduler quota in microseconds
     ///     cpu_shares: CPU shares (relative weight)
     ///     cpus: Number of CPUs
     ///     devices: List of device mappings, each a dict with PathOnHost, PathInContainer, CgroupPermissions
+    ///     dns: List of custom DNS servers
+    ///     dns_search: List of custom DNS search domains
     ///     entrypoint: Entrypoint as list (e.g., ["/bin/sh"])
     ///     env: Environment variables as list (e.g., ["VAR=value"])
     ///     expose: List of port mappings to expose as dicts with srcport, hostport, protocol
@@ -121,11 +133,15 @@ impl Pyo3Containers {
     ///     name: Container name
     ///     nano_cpus: CPU quota in units of 10^-9 CPUs
     ///     network_mode: Network mode (e.g., "bridge", "host", "none")
+    ///     oom_kill_disable: Disable the OOM killer for this container
+    ///     pids_limit: Tune the container's PID limit (-1 for unlimited)
     ///     privileged: Give extended privileges to this container
     ///     publish: List of ports to publish as dicts with port, protocol
     ///     publish_all_ports: Publish all exposed ports to random ports
+    ///     readonly_rootfs: Mount the container's root filesystem as read only
     ///     restart_policy: Restart policy as dict with name and maximum_retry_count
     ///     security_options: Security options as list (e.g., ["label=user:USER"])
+    ///     shm_size: Size of /dev/shm in bytes
     ///     stop_signal: Signal to stop the container
     ///     stop_signal_num: Signal number to stop the container
     ///     stop_timeout: Timeout for stopping the container (timedelta)
@@ -135,22 +151,35 @@ impl Pyo3Containers {
     ///     volumes: Volume bindings as list (e.g., ["/host:/container:rw"])
     ///     volumes_from: Mount volumes from other containers as list
     ///     working_dir: Working directory inside the container
+    ///     resolve_host_paths: When running inside a container ourselves
+    ///         (Docker-in-Docker), translate each bind mount's source path
+    ///         from this container's filesystem to the real host path the
+    ///         daemon needs, via [`crate::dind::host_path_of`]. No-op
+    ///         outside a container. Off by default.
     ///
     /// Returns:
     ///     Container: Created container instance
-    #[pyo3(signature = (image, *, attach_stderr=None, attach_stdin=None, attach_stdout=None, auto_remove=None, capabilities=None, command=None, cpu_shares=None, cpus=None, devices=None, entrypoint=None, env=None, expose=None, extra_hosts=None, labels=None, links=None, log_driver=None, memory=None, memory_swap=None, name=None, nano_cpus=None, network_mode=None, privileged=None, publish=None, publish_all_ports=None, restart_policy=None, security_options=None, stop_signal=None, stop_signal_num=None, stop_timeout=None, tty=None, user=None, userns_mode=None, volumes=None, volumes_from=None, working_dir=None))]
-    fn create(
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (image, *, attach_stderr=None, attach_stdin=None, attach_stdout=None, auto_remove=None, blkio_weight=None, capabilities=None, cap_drop=None, command=None, cpu_period=None, cpu_quota=None, cpu_shares=None, cpus=None, devices=None, dns=None, dns_search=None, entrypoint=None, env=None, expose=None, extra_hosts=None, labels=None, links=None, log_driver=None, memory=None, memory_swap=None, name=None, nano_cpus=None, network_mode=None, oom_kill_disable=None, pids_limit=None, privileged=None, publish=None, publish_all_ports=None, readonly_rootfs=None, restart_policy=None, security_options=None, shm_size=None, stop_signal=None, stop_signal_num=None, stop_timeout=None, tty=None, user=None, userns_mode=None, volumes=None, volumes_from=None, working_dir=None, resolve_host_paths=None))]
+    pub fn create(
         &self,
+        py: Python<'_>,
         image: &str,
         attach_stderr: Option<bool>,
         attach_stdin: Option<bool>,
         attach_stdout: Option<bool>,
         auto_remove: Option<bool>,
+        blkio_weight: Option<u16>,
         capabilities: Option<&Bound<'_, PyList>>,
+        cap_drop: Option<&Bound<'_, PyList>>,
         command: Option<&Bound<'_, PyList>>,
+        cpu_period: Option<u64>,
+        cpu_quota: Option<i64>,
         cpu_shares: Option<u32>,
         cpus: Option<f64>,
         devices: Option<&Bound<'_, PyList>>,
+        dns: Option<&Bound<'_, PyList>>,
+        dns_search: Option<&Bound<'_, PyList>>,
         entrypoint: Option<&Bound<'_, PyList>>,
         env: Option<&Bound<'_, PyList>>,
         expose: Option<&Bound<'_, PyList>>,
@@ -163,11 +192,15 @@ impl Pyo3Containers {
         name: Option<&str>,
         nano_cpus: Option<u64>,
         network_mode: Option<&str>,
+        oom_kill_disable: Option<bool>,
+        pids_limit: Option<i64>,
         privileged: Option<bool>,
         publish: Option<&Bound<'_, PyList>>,
         publish_all_ports: Option<bool>,
+        readonly_rootfs: Option<bool>,
         restart_policy: Option<&Bound<'_, PyDict>>, // name,maximum_retry_count,
         security_options: Option<&Bound<'_, PyList>>,
+        shm_size: Option<u64>,
         stop_signal: Option<&str>,
         stop_signal_num: Option<u64>,
         stop_timeout: Option<&Bound<'_, PyDelta>>,
@@ -177,108 +210,92 @@ impl Pyo3Containers {
         volumes: Option<&Bound<'_, PyList>>,
         volumes_from: Option<&Bound<'_, PyList>>,
         working_dir: Option<&str>,
+        resolve_host_paths: Option<bool>,
     ) -> PyResult<Pyo3Container> {
+        if let Some(weight) = blkio_weight {
+            if !(10..=1000).contains(&weight) {
+                return Err(exceptions::PyValueError::new_err(
+                    "blkio_weight must be between 10 and 1000",
+                ));
+            }
+        }
+
         let mut create_opts = ContainerCreateOpts::builder().image(image);
 
-        let links: Option<Vec<String>> = if links.is_some() {
-            links.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let links: Option<Vec<String>> = links.map(|v| v.extract()).transpose()?;
         let links: Option<Vec<&str>> = links
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let capabilities_strings: Option<Vec<String>> = if capabilities.is_some() {
-            capabilities.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let capabilities_strings: Option<Vec<String>> = capabilities.map(|v| v.extract()).transpose()?;
         let capabilities: Option<Vec<&str>> = capabilities_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let command_strings: Option<Vec<String>> = if command.is_some() {
-            command.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let cap_drop_strings: Option<Vec<String>> = cap_drop.map(|v| v.extract()).transpose()?;
+        let cap_drop: Option<Vec<&str>> = cap_drop_strings
+            .as_ref()
+            .map(|v| v.iter().map(|s| s.as_str()).collect());
+
+        let dns_strings: Option<Vec<String>> = dns.map(|v| v.extract()).transpose()?;
+        let dns: Option<Vec<&str>> = dns_strings
+            .as_ref()
+            .map(|v| v.iter().map(|s| s.as_str()).collect());
+
+        let dns_search_strings: Option<Vec<String>> = dns_search.map(|v| v.extract()).transpose()?;
+        let dns_search: Option<Vec<&str>> = dns_search_strings
+            .as_ref()
+            .map(|v| v.iter().map(|s| s.as_str()).collect());
+
+        let command_strings: Option<Vec<String>> = command.map(|v| v.extract()).transpose()?;
         let command: Option<Vec<&str>> = command_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let entrypoint_strings: Option<Vec<String>> = if entrypoint.is_some() {
-            entrypoint.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let entrypoint_strings: Option<Vec<String>> = entrypoint.map(|v| v.extract()).transpose()?;
         let entrypoint: Option<Vec<&str>> = entrypoint_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let env_strings: Option<Vec<String>> = if env.is_some() {
-            env.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let env_strings: Option<Vec<String>> = env.map(|v| v.extract()).transpose()?;
         let env: Option<Vec<&str>> = env_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let extra_hosts_strings: Option<Vec<String>> = if extra_hosts.is_some() {
-            extra_hosts.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let extra_hosts_strings: Option<Vec<String>> = extra_hosts.map(|v| v.extract()).transpose()?;
         let extra_hosts: Option<Vec<&str>> = extra_hosts_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let security_options_strings: Option<Vec<String>> = if security_options.is_some() {
-            security_options.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let security_options_strings: Option<Vec<String>> = security_options.map(|v| v.extract()).transpose()?;
         let security_options: Option<Vec<&str>> = security_options_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let volumes_strings: Option<Vec<String>> = if volumes.is_some() {
-            volumes.unwrap().extract().unwrap()
-        } else {
-            None
+        let volumes_strings: Option<Vec<String>> = match volumes.map(|v| v.extract()).transpose()? {
+            Some(requested) if resolve_host_paths.unwrap_or(false) => Some(
+                requested
+                    .into_iter()
+                    .map(|v: String| resolve_volume_host_path(&self.1, &v))
+                    .collect::<Result<Vec<String>, DockerPyo3Error>>()?,
+            ),
+            other => other,
         };
         let volumes: Option<Vec<&str>> = volumes_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let volumes_from_strings: Option<Vec<String>> = if volumes_from.is_some() {
-            volumes_from.unwrap().extract().unwrap()
-        } else {
-            None
-        };
+        let volumes_from_strings: Option<Vec<String>> = volumes_from.map(|v| v.extract()).transpose()?;
         let volumes_from: Option<Vec<&str>> = volumes_from_strings
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
 
-        let devices_vec: Option<Vec<HashMap<String, String>>> = if devices.is_some() {
-            let list = devices.unwrap();
-            let mut result = Vec::new();
-            for item in list.iter() {
-                let dict: HashMap<String, String> = item.extract().unwrap();
-                result.push(dict);
-            }
-            Some(result)
-        } else {
-            None
-        };
+        let devices_vec: Option<Vec<HashMap<String, String>>> = devices
+            .map(|list| list.iter().map(|item| item.extract()).collect::<PyResult<Vec<_>>>())
+            .transpose()?;
         let devices = devices_vec;
 
-        let labels_map: Option<HashMap<String, String>> = if labels.is_some() {
-            Some(labels.unwrap().extract().unwrap())
-        } else {
-            None
-        };
+        let labels_map: Option<HashMap<String, String>> = labels.map(|v| v.extract()).transpose()?;
         let labels: Option<HashMap<&str, &str>> = labels_map
             .as_ref()
             .map(|m| m.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect());
@@ -291,6 +308,9 @@ impl Pyo3Containers {
         bo_setter!(attach_stdin, create_opts);
         bo_setter!(attach_stdout, create_opts);
         bo_setter!(auto_remove, create_opts);
+        bo_setter!(blkio_weight, create_opts);
+        bo_setter!(cpu_period, create_opts);
+        bo_setter!(cpu_quota, create_opts);
         bo_setter!(cpu_shares, create_opts);
         bo_setter!(cpus, create_opts);
         bo_setter!(log_driver, create_opts);
@@ -299,7 +319,11 @@ impl Pyo3Containers {
         bo_setter!(name, create_opts);
         bo_setter!(nano_cpus, create_opts);
         bo_setter!(network_mode, create_opts);
+        bo_setter!(oom_kill_disable, create_opts);
+        bo_setter!(pids_limit, create_opts);
         bo_setter!(privileged, create_opts);
+        bo_setter!(readonly_rootfs, create_opts);
+        bo_setter!(shm_size, create_opts);
         bo_setter!(stop_signal, create_opts);
         bo_setter!(stop_signal_num, create_opts);
         bo_setter!(tty, create_opts);
@@ -310,7 +334,10 @@ impl Pyo3Containers {
         bo_setter!(devices, create_opts);
         bo_setter!(links, create_opts);
         bo_setter!(capabilities, create_opts);
+        bo_setter!(cap_drop, create_opts);
         bo_setter!(command, create_opts);
+        bo_setter!(dns, create_opts);
+        bo_setter!(dns_search, create_opts);
         bo_setter!(entrypoint, create_opts);
         bo_setter!(env, create_opts);
         bo_setter!(extra_hosts, create_opts);
@@ -407,51 +434,338 @@ impl Pyo3Containers {
         // bo_setter!(expose, create_opts);
         // bo_setter!(publish, create_opts);
 
-        let rv = __containers_create(&self.0, &create_opts.build());
+        let rv = py.allow_threads(|| __containers_create(&self.0, &create_opts.build()));
         match rv {
-            Ok(rv) => Ok(Pyo3Container(rv)),
+            Ok(rv) => Ok(Pyo3Container(rv, self.1.clone())),
             Err(rv) => Err(py_sys_exception!(rv)),
         }
     }
+
+    /// Converge a container named `name` to the requested spec, the way
+    /// `create()` alone can't: if no container by that name exists yet it's
+    /// created; if one exists and already matches, it's left alone (started
+    /// if it happens to be stopped); if one exists but a meaningful field
+    /// has drifted, it's stopped, deleted, and recreated. Accepts the same
+    /// keyword arguments as `create()`.
+    ///
+    /// The live/requested comparison only looks at `image`, `command`,
+    /// `env`, `labels`, `restart_policy`, `memory`, and `publish` - fields
+    /// this crate can't see (e.g. `network_mode`) never trigger a
+    /// recreate.
+    ///
+    /// Args:
+    ///     image: Image name to use for the container
+    ///     name: Container name to converge (required, unlike `create()`)
+    ///     check: Compute and return the plan without creating, starting,
+    ///         stopping, or deleting anything
+    ///     (all other keyword arguments are the same as `create()`)
+    ///
+    /// Returns:
+    ///     dict: `{"changed": bool, "actions": [str, ...], "container_id": str}`
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (image, *, name, check=false, attach_stderr=None, attach_stdin=None, attach_stdout=None, auto_remove=None, blkio_weight=None, capabilities=None, cap_drop=None, command=None, cpu_period=None, cpu_quota=None, cpu_shares=None, cpus=None, devices=None, dns=None, dns_search=None, entrypoint=None, env=None, expose=None, extra_hosts=None, labels=None, links=None, log_driver=None, memory=None, memory_swap=None, nano_cpus=None, network_mode=None, oom_kill_disable=None, pids_limit=None, privileged=None, publish=None, publish_all_ports=None, readonly_rootfs=None, restart_policy=None, security_options=None, shm_size=None, stop_signal=None, stop_signal_num=None, stop_timeout=None, tty=None, user=None, userns_mode=None, volumes=None, volumes_from=None, working_dir=None, resolve_host_paths=None))]
+    fn ensure(
+        &self,
+        py: Python<'_>,
+        image: &str,
+        name: &str,
+        check: bool,
+        attach_stderr: Option<bool>,
+        attach_stdin: Option<bool>,
+        attach_stdout: Option<bool>,
+        auto_remove: Option<bool>,
+        blkio_weight: Option<u16>,
+        capabilities: Option<&Bound<'_, PyList>>,
+        cap_drop: Option<&Bound<'_, PyList>>,
+        command: Option<&Bound<'_, PyList>>,
+        cpu_period: Option<u64>,
+        cpu_quota: Option<i64>,
+        cpu_shares: Option<u32>,
+        cpus: Option<f64>,
+        devices: Option<&Bound<'_, PyList>>,
+        dns: Option<&Bound<'_, PyList>>,
+        dns_search: Option<&Bound<'_, PyList>>,
+        entrypoint: Option<&Bound<'_, PyList>>,
+        env: Option<&Bound<'_, PyList>>,
+        expose: Option<&Bound<'_, PyList>>,
+        extra_hosts: Option<&Bound<'_, PyList>>,
+        labels: Option<&Bound<'_, PyDict>>,
+        links: Option<&Bound<'_, PyList>>,
+        log_driver: Option<&str>,
+        memory: Option<u64>,
+        memory_swap: Option<i64>,
+        nano_cpus: Option<u64>,
+        network_mode: Option<&str>,
+        oom_kill_disable: Option<bool>,
+        pids_limit: Option<i64>,
+        privileged: Option<bool>,
+        publish: Option<&Bound<'_, PyList>>,
+        publish_all_ports: Option<bool>,
+        readonly_rootfs: Option<bool>,
+        restart_policy: Option<&Bound<'_, PyDict>>,
+        security_options: Option<&Bound<'_, PyList>>,
+        shm_size: Option<u64>,
+        stop_signal: Option<&str>,
+        stop_signal_num: Option<u64>,
+        stop_timeout: Option<&Bound<'_, PyDelta>>,
+        tty: Option<bool>,
+        user: Option<&str>,
+        userns_mode: Option<&str>,
+        volumes: Option<&Bound<'_, PyList>>,
+        volumes_from: Option<&Bound<'_, PyList>>,
+        working_dir: Option<&str>,
+        resolve_host_paths: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let existing = Container::new(self.1.clone(), name.to_string());
+        let inspected = py.allow_threads(|| __container_inspect_checked(&existing));
+
+        let mut actions: Vec<String> = Vec::new();
+        let mut changed = false;
+        let mut container_id = name.to_string();
+
+        macro_rules! recreate {
+            () => {
+                self.create(
+                    py, image, attach_stderr, attach_stdin, attach_stdout, auto_remove,
+                    blkio_weight, capabilities, cap_drop, command, cpu_period, cpu_quota,
+                    cpu_shares, cpus, devices, dns, dns_search, entrypoint, env, expose,
+                    extra_hosts, labels, links, log_driver, memory, memory_swap, Some(name),
+                    nano_cpus, network_mode, oom_kill_disable, pids_limit, privileged, publish,
+                    publish_all_ports, readonly_rootfs, restart_policy, security_options,
+                    shm_size, stop_signal, stop_signal_num, stop_timeout, tty, user, userns_mode,
+                    volumes, volumes_from, working_dir, resolve_host_paths,
+                )
+            };
+        }
+
+        match inspected {
+            Err(e) if is_not_found_error(&e) => {
+                actions.push(format!("create '{name}' (no existing container by that name)"));
+                changed = true;
+                if !check {
+                    container_id = recreate!()?.id();
+                }
+            }
+            Err(e) => return Err(DockerPyo3Error::from(e).into()),
+            Ok(ci) => {
+                let live = serde_yaml::to_value(&ci)
+                    .map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+                container_id = live
+                    .get("Id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(name)
+                    .to_string();
+                let running = live
+                    .get("State")
+                    .and_then(|s| s.get("Running"))
+                    .and_then(|r| r.as_bool())
+                    .unwrap_or(false);
+
+                if spec_matches_live(&live, image, command, env, labels, restart_policy, memory, publish)? {
+                    if running {
+                        actions.push(format!("'{name}' already matches the requested spec"));
+                    } else {
+                        actions.push(format!("start '{name}'"));
+                        changed = true;
+                        if !check {
+                            py.allow_threads(|| __container_start(&existing))
+                                .map_err(DockerPyo3Error::from)?;
+                        }
+                    }
+                } else {
+                    actions.push(format!("recreate '{name}' (live config has drifted from the requested spec)"));
+                    changed = true;
+                    if !check {
+                        if running {
+                            py.allow_threads(|| __container_stop(&existing, None))
+                                .map_err(DockerPyo3Error::from)?;
+                        }
+                        py.allow_threads(|| __container_delete(&existing))
+                            .map_err(DockerPyo3Error::from)?;
+                        container_id = recreate!()?.id();
+                    }
+                }
+            }
+        }
+
+        let result = PyDict::new(py);
+        result.set_item("changed", changed)?;
+        result.set_item("actions", actions)?;
+        result.set_item("container_id", container_id)?;
+        Ok(result.into())
+    }
 }
 
-#[tokio::main]
-async fn __containers_list(
-    containers: &Containers,
-    opts: &ContainerListOpts,
-) -> Vec<ContainerSummary> {
-    let x = containers.list(opts).await;
-    x.unwrap()
+/// Inspect `container`, surfacing a 404 as a normal `Err` instead of the
+/// panic `__container_inspect` (used by `Container.inspect()`) would give -
+/// `Containers.ensure()` needs to tell "doesn't exist yet" apart from a
+/// real failure.
+fn __container_inspect_checked(container: &Container) -> Result<ContainerInspect200Response, docker_api::Error> {
+    get_runtime().block_on(container.inspect())
 }
 
-#[tokio::main]
-async fn __containers_prune(
+fn is_not_found_error(error: &docker_api::Error) -> bool {
+    let message = error.to_string();
+    message.contains("404") || message.contains("No such container") || message.contains("not found")
+}
+
+/// Compare the requested `create()` spec against a live `inspect()`
+/// snapshot, navigated generically (the same way [`augment_stats`] reads
+/// `/stats`) rather than through `ContainerInspect200Response`'s nested
+/// Rust struct fields. Only the fields `Containers.ensure()` documents
+/// comparing are checked; anything else in `live` is ignored.
+#[allow(clippy::too_many_arguments)]
+fn spec_matches_live(
+    live: &serde_yaml::Value,
+    image: &str,
+    command: Option<&Bound<'_, PyList>>,
+    env: Option<&Bound<'_, PyList>>,
+    labels: Option<&Bound<'_, PyDict>>,
+    restart_policy: Option<&Bound<'_, PyDict>>,
+    memory: Option<u64>,
+    publish: Option<&Bound<'_, PyList>>,
+) -> PyResult<bool> {
+    let get = |path: &[&str]| -> Option<&serde_yaml::Value> {
+        let mut cur = live;
+        for key in path {
+            cur = cur.get(*key)?;
+        }
+        Some(cur)
+    };
+
+    if get(&["Config", "Image"]).and_then(|v| v.as_str()) != Some(image) {
+        return Ok(false);
+    }
+
+    if let Some(command) = command {
+        let requested: Vec<String> = command.extract()?;
+        let live_command: Vec<String> = get(&["Config", "Cmd"])
+            .and_then(|v| v.as_sequence())
+            .map(|seq| seq.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        if requested != live_command {
+            return Ok(false);
+        }
+    }
+
+    if let Some(env) = env {
+        let requested: std::collections::HashSet<String> = env.extract::<Vec<String>>()?.into_iter().collect();
+        let live_env: std::collections::HashSet<String> = get(&["Config", "Env"])
+            .and_then(|v| v.as_sequence())
+            .map(|seq| seq.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        if !requested.is_subset(&live_env) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(labels) = labels {
+        let requested: HashMap<String, String> = labels.extract()?;
+        let live_labels: HashMap<String, String> = get(&["Config", "Labels"])
+            .and_then(|v| v.as_mapping())
+            .map(|m| {
+                m.iter()
+                    .filter_map(|(k, v)| Some((k.as_str()?.to_string(), v.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for (key, value) in &requested {
+            if live_labels.get(key) != Some(value) {
+                return Ok(false);
+            }
+        }
+    }
+
+    if let Some(restart_policy) = restart_policy {
+        let requested_name: String = restart_policy
+            .get_item("name")?
+            .expect("restart_policy requires 'name' key")
+            .extract()?;
+        let live_name = get(&["HostConfig", "RestartPolicy", "Name"]).and_then(|v| v.as_str());
+        if Some(requested_name.as_str()) != live_name {
+            return Ok(false);
+        }
+    }
+
+    if let Some(memory) = memory {
+        if get(&["HostConfig", "Memory"]).and_then(|v| v.as_u64()) != Some(memory) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(publish) = publish {
+        // `create()`'s `publish` only exposes the port (no host binding),
+        // so it shows up under `Config.ExposedPorts`, not `PortBindings`.
+        let live_bindings = get(&["Config", "ExposedPorts"]).and_then(|v| v.as_mapping());
+        for item in publish.iter() {
+            let port_dict: &Bound<'_, PyDict> = item.downcast()?;
+            let port: u32 = port_dict.get_item("port")?.expect("port required").extract()?;
+            let protocol: String = match port_dict.get_item("protocol")? {
+                Some(p) => p.extract()?,
+                None => "tcp".to_string(),
+            };
+            let key = serde_yaml::Value::from(format!("{port}/{protocol}"));
+            let is_bound = live_bindings.map(|m| m.contains_key(&key)).unwrap_or(false);
+            if !is_bound {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+fn __containers_list(containers: &Containers, opts: &ContainerListOpts) -> Vec<ContainerSummary> {
+    get_runtime().block_on(async {
+        let x = containers.list(opts).await;
+        x.unwrap()
+    })
+}
+
+fn __containers_prune(
     containers: &Containers,
     opts: &ContainerPruneOpts,
 ) -> Result<ContainerPrune200Response, docker_api::Error> {
-    containers.prune(opts).await
+    get_runtime().block_on(containers.prune(opts))
 }
 
-#[tokio::main]
-async fn __containers_create(
-    containers: &Containers,
-    opts: &ContainerCreateOpts,
-) -> Result<Container, docker_api::Error> {
-    containers.create(opts).await
+fn __containers_create(containers: &Containers, opts: &ContainerCreateOpts) -> Result<Container, docker_api::Error> {
+    get_runtime().block_on(containers.create(opts))
+}
+
+/// Translate a `"src:dst[:mode]"` (or bare `src`) bind-mount string's
+/// source path via [`crate::dind::host_path_of`] - `Containers::create`'s
+/// `resolve_host_paths` opt-in. Leaves named volumes (a source with no
+/// leading `/`) untouched, since those aren't host filesystem paths.
+fn resolve_volume_host_path(docker: &Docker, volume: &str) -> Result<String, DockerPyo3Error> {
+    let mut parts = volume.splitn(3, ':');
+    let source = parts.next().unwrap_or(volume);
+    let rest: Vec<&str> = parts.collect();
+
+    if !source.starts_with('/') {
+        return Ok(volume.to_string());
+    }
+
+    let resolved = crate::dind::host_path_of(docker, source)?;
+    if rest.is_empty() {
+        Ok(resolved)
+    } else {
+        Ok(format!("{resolved}:{}", rest.join(":")))
+    }
 }
 
 #[pymethods]
 impl Pyo3Container {
     #[new]
     fn new(docker: Pyo3Docker, id: String) -> Self {
-        Pyo3Container(Container::new(docker.0, id))
+        Pyo3Container(Container::new(docker.0.clone(), id), docker.0)
     }
 
     /// Get the container ID.
     ///
     /// Returns:
     ///     str: Container ID
-    fn id(&self) -> String {
+    pub fn id(&self) -> String {
         self.0.id().to_string()
     }
 
@@ -459,8 +773,8 @@ impl Pyo3Container {
     ///
     /// Returns:
     ///     dict: Detailed container information including config, state, mounts, etc.
-    fn inspect(&self) -> PyResult<Py<PyAny>> {
-        let ci = __container_inspect(&self.0);
+    fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let ci = py.allow_threads(|| __container_inspect(&self.0));
         Ok(pythonize_this!(ci))
     }
 
@@ -479,6 +793,7 @@ impl Pyo3Container {
     #[pyo3(signature = (stdout=None, stderr=None, timestamps=None, n_lines=None, all=None, since=None))]
     fn logs(
         &self,
+        py: Python<'_>,
         stdout: Option<bool>,
         stderr: Option<bool>,
         timestamps: Option<bool>,
@@ -503,7 +818,87 @@ impl Pyo3Container {
             log_opts = log_opts.since(&rs_since);
         }
 
-        __container_logs(&self.0, &log_opts.build())
+        py.allow_threads(|| __container_logs(&self.0, &log_opts.build()))
+    }
+
+    /// Get container logs as a lazy iterator instead of one buffered
+    /// `String`, so `follow=True` can tail a live container without
+    /// reading the whole history into memory first.
+    ///
+    /// Args:
+    ///     stdout: Include stdout
+    ///     stderr: Include stderr
+    ///     timestamps: Include timestamps
+    ///     n_lines: Number of lines to return from the end of logs
+    ///     since: Only return logs since this datetime
+    ///     until: Only return logs before this datetime
+    ///     follow: Keep the connection open and yield new lines as they're written
+    ///
+    /// Returns:
+    ///     LogStream: Iterator yielding decoded log chunks as they arrive
+    #[pyo3(signature = (stdout=None, stderr=None, timestamps=None, n_lines=None, since=None, until=None, follow=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn logs_stream(
+        &self,
+        py: Python<'_>,
+        stdout: Option<bool>,
+        stderr: Option<bool>,
+        timestamps: Option<bool>,
+        n_lines: Option<usize>,
+        since: Option<&Bound<'_, PyDateTime>>,
+        until: Option<&Bound<'_, PyDateTime>>,
+        follow: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let since: Option<DateTime<Utc>> = since.map(|s| s.extract()).transpose()?;
+        let until: Option<DateTime<Utc>> = until.map(|u| u.extract()).transpose()?;
+
+        let path = build_logs_path(
+            self.0.id(),
+            stdout,
+            stderr,
+            timestamps,
+            n_lines,
+            since,
+            until,
+            follow,
+        );
+
+        let log_stream = Pyo3LogStream::open(self.1.clone(), path)?;
+        Py::new(py, log_stream).map(|p| p.into_any())
+    }
+
+    /// Sample the container's live resource usage from `/containers/{id}/stats`.
+    ///
+    /// The daemon's CPU counters are cumulative since boot, so a usable
+    /// percentage needs two samples: `cpu_delta` (this sample's
+    /// `cpu_stats.cpu_usage.total_usage` minus `precpu_stats`' own) over
+    /// `system_delta` (same for `system_cpu_usage`), scaled by
+    /// `online_cpus`. The very first sample in a stream has no prior
+    /// sample to diff against and reports `cpu_percent: 0.0` until the
+    /// second one arrives. `mem_usage`, `mem_limit`, and a derived
+    /// `mem_percent` are added alongside the raw fields, as are
+    /// `net_rx_bytes`/`net_tx_bytes` (summed across every network
+    /// interface) and `blkio_read_bytes`/`blkio_write_bytes` (summed
+    /// across every block device), so callers get a ready-to-plot
+    /// snapshot without reimplementing the math in Python.
+    ///
+    /// Args:
+    ///     stream: if True, return a `ContainerStats` iterator yielding a
+    ///         new snapshot dict as the daemon pushes one; if False
+    ///         (default) return a single snapshot dict.
+    #[pyo3(signature = (stream=false))]
+    fn stats(&self, py: Python<'_>, stream: bool) -> PyResult<Py<PyAny>> {
+        if stream {
+            let stats_stream = Pyo3ContainerStats::open(self.1.clone(), self.0.id().to_string())?;
+            return Py::new(py, stats_stream).map(|p| p.into_any());
+        }
+
+        let path = format!("/containers/{}/stats?stream=false", self.0.id());
+        let docker = self.1.clone();
+        let value = py
+            .allow_threads(|| __container_stats_once(&docker, &path))
+            .map_err(DockerPyo3Error::from)?;
+        Ok(pythonize_this!(augment_stats(value)))
     }
 
     /// Remove the container (not implemented yet).
@@ -520,8 +915,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be deleted
-    fn delete(&self) -> PyResult<()> {
-        let rv = __container_delete(&self.0);
+    pub fn delete(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_delete(&self.0));
         if rv.is_ok() {
             Ok(())
         } else {
@@ -531,19 +926,42 @@ impl Pyo3Container {
         }
     }
 
-    // fn top(&self) -> PyResult<()> {
-    //     Err(exceptions::PyNotImplementedError::new_err(
-    //         "This method is not available yet.",
-    //     ))
-    // }
+    /// List the processes running inside the container (`GET
+    /// /containers/{id}/top`), without needing to exec `ps` yourself.
+    ///
+    /// Args:
+    ///     ps_args: Arguments forwarded to the daemon's `ps` invocation
+    ///         (e.g. `"aux"`); defaults to the daemon's own default
+    ///
+    /// Returns:
+    ///     dict: `{"titles": [str, ...], "processes": [[str, ...], ...]}`
+    #[pyo3(signature = (ps_args=None))]
+    fn top(&self, py: Python<'_>, ps_args: Option<&str>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __container_top(&self.0, ps_args));
 
-    // fn export(&self, docker_path: &str, local_path: &str) -> PyResult<()> {
-    //     let bytes = self.0.export();
-    //     let mut archive = Archive::new(&bytes[..]);
-    //     archive.unpack(local_path);
+        match rv {
+            Ok(top) => {
+                let result = PyDict::new(py);
+                result.set_item("titles", top.titles.unwrap_or_default())?;
+                result.set_item("processes", top.processes.unwrap_or_default())?;
+                Ok(result.into())
+            }
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
 
-    //     Ok(())
-    // }
+    /// Stream the whole container filesystem out as a tar
+    /// (`GET /containers/{id}/export`) and either write it to `local_path`
+    /// verbatim or, if `local_path` already exists as a directory, unpack
+    /// it there - the same either-or `write_or_unpack_tar` uses for
+    /// `get_archive`.
+    fn export(&self, py: Python<'_>, local_path: &str) -> PyResult<()> {
+        let bytes = py
+            .allow_threads(|| __container_export(&self.0))
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))?;
+        write_or_unpack_tar(&bytes, local_path)
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))
+    }
 
     /// Start the container.
     ///
@@ -552,8 +970,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be started
-    fn start(&self) -> PyResult<()> {
-        let rv = __container_start(&self.0);
+    pub fn start(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_start(&self.0));
 
         match rv {
             Ok(_rv) => Ok(()),
@@ -573,7 +991,7 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be stopped
-    fn stop(&self, wait: Option<&Bound<'_, PyDelta>>) -> PyResult<()> {
+    pub fn stop(&self, py: Python<'_>, wait: Option<&Bound<'_, PyDelta>>) -> PyResult<()> {
         let wait: Option<std::time::Duration> = wait.map(|wait| {
             wait.extract::<chrono::Duration>()
                 .unwrap()
@@ -581,7 +999,7 @@ impl Pyo3Container {
                 .unwrap()
         });
 
-        let rv = __container_stop(&self.0, wait);
+        let rv = py.allow_threads(|| __container_stop(&self.0, wait));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -600,7 +1018,7 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be restarted
-    fn restart(&self, wait: Option<&Bound<'_, PyDelta>>) -> PyResult<()> {
+    fn restart(&self, py: Python<'_>, wait: Option<&Bound<'_, PyDelta>>) -> PyResult<()> {
         let wait: Option<std::time::Duration> = wait.map(|wait| {
             wait.extract::<chrono::Duration>()
                 .unwrap()
@@ -608,7 +1026,7 @@ impl Pyo3Container {
                 .unwrap()
         });
 
-        let rv = __container_restart(&self.0, wait);
+        let rv = py.allow_threads(|| __container_restart(&self.0, wait));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -627,8 +1045,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be killed
-    fn kill(&self, signal: Option<&str>) -> PyResult<()> {
-        let rv = __container_kill(&self.0, signal);
+    fn kill(&self, py: Python<'_>, signal: Option<&str>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_kill(&self.0, signal));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -647,8 +1065,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be renamed
-    fn rename(&self, name: &str) -> PyResult<()> {
-        let rv = __container_rename(&self.0, name);
+    fn rename(&self, py: Python<'_>, name: &str) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_rename(&self.0, name));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -664,8 +1082,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be paused
-    fn pause(&self) -> PyResult<()> {
-        let rv = __container_pause(&self.0);
+    fn pause(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_pause(&self.0));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -681,8 +1099,8 @@ impl Pyo3Container {
     ///
     /// Raises:
     ///     SystemError: If the container cannot be unpaused
-    fn unpause(&self) -> PyResult<()> {
-        let rv = __container_unpause(&self.0);
+    fn unpause(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_unpause(&self.0));
         match rv {
             Ok(_rv) => Ok(()),
             Err(_rv) => Err(exceptions::PySystemError::new_err(
@@ -691,75 +1109,210 @@ impl Pyo3Container {
         }
     }
 
-    /// Wait for the container to stop.
+    /// Wait for the container to reach `condition`, the daemon's own
+    /// default being "not-running" (i.e. exited) when omitted.
+    ///
+    /// Args:
+    ///     condition: One of "not-running", "next-exit", or "removed"
+    ///     timeout: Maximum time to wait (timedelta); waits forever if omitted
     ///
     /// Returns:
     ///     dict: Wait response including status code
-    fn wait(&self) -> Py<PyAny> {
-        let rv = __container_wait(&self.0).unwrap();
-        pythonize_this!(rv)
+    ///
+    /// Raises:
+    ///     TimeoutError: If `timeout` elapses before `condition` is reached
+    #[pyo3(signature = (condition=None, timeout=None))]
+    fn wait(
+        &self,
+        py: Python<'_>,
+        condition: Option<&str>,
+        timeout: Option<&Bound<'_, PyDelta>>,
+    ) -> PyResult<Py<PyAny>> {
+        let timeout_duration = timeout
+            .map(|t| t.extract::<chrono::Duration>())
+            .transpose()?
+            .map(|d| d.to_std().unwrap_or_default());
+
+        let docker = self.1.clone();
+        let id = self.0.id().to_string();
+        let rv = py.allow_threads(|| __container_wait(&docker, &id, condition, timeout_duration));
+
+        match rv {
+            Ok(Some(value)) => Ok(pythonize_this!(value)),
+            Ok(None) => Err(exceptions::PyTimeoutError::new_err(format!(
+                "container did not reach '{}' within the timeout",
+                condition.unwrap_or("not-running")
+            ))),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
     }
 
-    /// Execute a command in the running container.
+    /// Run a command inside the running container and wait for it to finish.
+    ///
+    /// Drives the daemon's two-step `/containers/{id}/exec` create +
+    /// `/exec/{id}/start` flow against the shared runtime, then reads the
+    /// exit code back from `/exec/{id}/json`.
     ///
     /// Args:
     ///     command: Command to execute as list (e.g., ["/bin/sh", "-c", "ls"])
     ///     env: Environment variables as list (e.g., ["VAR=value"])
-    ///     attach_stdout: Attach to stdout
-    ///     attach_stderr: Attach to stderr
-    ///     detach_keys: Override key sequence for detaching
-    ///     tty: Allocate a pseudo-TTY
-    ///     privileged: Run with extended privileges
-    ///     user: Username or UID
     ///     working_dir: Working directory for the exec session
+    ///     user: Username or UID
+    ///     privileged: Run with extended privileges
+    ///     tty: Allocate a pseudo-TTY; when set, the daemon returns a single
+    ///         raw byte stream with no stdout/stderr framing, so everything
+    ///         is captured as stdout
+    ///     attach_stdout: Capture the command's stdout
+    ///     attach_stderr: Capture the command's stderr
     ///
     /// Returns:
-    ///     None
+    ///     dict: {"stdout": str, "stderr": str, "exit_code": int | None}
     ///
     /// Raises:
-    ///     SystemError: If the command cannot be executed
+    ///     DockerException: If the exec session could not be created or started
+    #[pyo3(signature = (command, *, env=None, working_dir=None, user=None, privileged=None, tty=None, attach_stdout=true, attach_stderr=true))]
+    #[allow(clippy::too_many_arguments)]
     fn exec(
         &self,
+        py: Python<'_>,
         command: &Bound<'_, PyList>,
         env: Option<&Bound<'_, PyList>>,
-        attach_stdout: Option<bool>,
-        attach_stderr: Option<bool>,
-        detach_keys: Option<&str>,
-        tty: Option<bool>,
-        privileged: Option<bool>,
+        working_dir: Option<&str>,
         user: Option<&str>,
+        privileged: Option<bool>,
+        tty: Option<bool>,
+        attach_stdout: bool,
+        attach_stderr: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let command_strings: Vec<String> = command.extract().unwrap();
+        let command: Vec<&str> = command_strings.iter().map(|s| s.as_str()).collect();
+        let mut exec_opts = ExecCreateOpts::builder()
+            .command(command)
+            .attach_stdout(attach_stdout)
+            .attach_stderr(attach_stderr);
+
+        if let Some(env) = env {
+            let env_strings: Vec<String> = env.extract().unwrap();
+            let env: Vec<&str> = env_strings.iter().map(|s| s.as_str()).collect();
+            exec_opts = exec_opts.env(env);
+        }
+
+        bo_setter!(tty, exec_opts);
+        bo_setter!(privileged, exec_opts);
+        bo_setter!(user, exec_opts);
+        bo_setter!(working_dir, exec_opts);
+
+        let rv = py.allow_threads(|| {
+            __container_exec(&self.1, self.0.id(), &exec_opts.build(), tty.unwrap_or(false))
+        });
+
+        match rv {
+            Ok(result) => Ok(pythonize_this!(result)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Create an exec session without running it, returning a handle that
+    /// can be started, read from, and then inspected for its exit code -
+    /// unlike `exec()`, which creates, runs, and drains the session in one
+    /// call.
+    ///
+    /// Args:
+    ///     command: Command to execute as list (e.g., ["/bin/sh", "-c", "ls"])
+    ///     env: Environment variables as list (e.g., ["VAR=value"])
+    ///     working_dir: Working directory for the exec session
+    ///     user: Username or UID
+    ///     privileged: Run with extended privileges
+    ///     tty: Allocate a pseudo-TTY; when set, `Exec.start()` folds the
+    ///         daemon's unmultiplexed raw stream into stdout
+    ///     attach_stdout: Capture the command's stdout
+    ///     attach_stderr: Capture the command's stderr
+    ///
+    /// Returns:
+    ///     Exec: Handle to the created (but not yet started) exec session
+    ///
+    /// Raises:
+    ///     DockerException: If the exec session could not be created
+    #[pyo3(signature = (command, *, env=None, working_dir=None, user=None, privileged=None, tty=None, attach_stdout=true, attach_stderr=true))]
+    #[allow(clippy::too_many_arguments)]
+    fn create_exec(
+        &self,
+        py: Python<'_>,
+        command: &Bound<'_, PyList>,
+        env: Option<&Bound<'_, PyList>>,
         working_dir: Option<&str>,
-    ) -> PyResult<()> {
+        user: Option<&str>,
+        privileged: Option<bool>,
+        tty: Option<bool>,
+        attach_stdout: bool,
+        attach_stderr: bool,
+    ) -> PyResult<Pyo3Exec> {
         let command_strings: Vec<String> = command.extract().unwrap();
         let command: Vec<&str> = command_strings.iter().map(|s| s.as_str()).collect();
-        let mut exec_opts = ExecCreateOpts::builder().command(command);
+        let mut exec_opts = ExecCreateOpts::builder()
+            .command(command)
+            .attach_stdout(attach_stdout)
+            .attach_stderr(attach_stderr);
 
-        if env.is_some() {
-            let env_strings: Vec<String> = env.unwrap().extract().unwrap();
+        if let Some(env) = env {
+            let env_strings: Vec<String> = env.extract().unwrap();
             let env: Vec<&str> = env_strings.iter().map(|s| s.as_str()).collect();
             exec_opts = exec_opts.env(env);
         }
 
-        bo_setter!(attach_stdout, exec_opts);
-        bo_setter!(attach_stderr, exec_opts);
         bo_setter!(tty, exec_opts);
-        bo_setter!(detach_keys, exec_opts);
         bo_setter!(privileged, exec_opts);
         bo_setter!(user, exec_opts);
         bo_setter!(working_dir, exec_opts);
 
-        let rv = __container_exec(&self.0, exec_opts.build());
-        let rv = rv.unwrap();
+        let rv = py.allow_threads(|| __exec_create(&self.1, self.0.id(), &exec_opts.build()));
+
         match rv {
-            Ok(_rv) => Ok(()),
-            Err(rv) => Err(exceptions::PySystemError::new_err(format!(
-                "Failed to exec container {rv}"
-            ))),
+            Ok(exec) => Ok(Pyo3Exec(exec, tty.unwrap_or(false))),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
         }
     }
 
-    fn copy_from(&self, src: &str, dst: &str) -> PyResult<()> {
-        let rv = __container_copy_from(&self.0, src);
+    /// Attach to the container's main process stdin/stdout/stderr, for
+    /// driving an interactive session (e.g. a shell started with
+    /// `tty=True`) - unlike `exec()`/`create_exec()`, which run a separate
+    /// command inside the container rather than talking to PID 1 itself.
+    ///
+    /// Args:
+    ///     stdin: Attach to stdin, so `Attach.write_stdin(...)` can feed it
+    ///     stdout: Attach to stdout
+    ///     stderr: Attach to stderr
+    ///     logs: Replay buffered logs before the live stream
+    ///     stream: Keep streaming after the logs replay
+    ///
+    /// Returns:
+    ///     Attach: Iterator yielding {"stream": "stdout"|"stderr", "data":
+    ///         bytes} dicts, with a `write_stdin(data)` method for writing
+    ///         back in
+    #[pyo3(signature = (stdin=None, stdout=None, stderr=None, logs=None, stream=None))]
+    fn attach(
+        &self,
+        py: Python<'_>,
+        stdin: Option<bool>,
+        stdout: Option<bool>,
+        stderr: Option<bool>,
+        logs: Option<bool>,
+        stream: Option<bool>,
+    ) -> PyResult<Pyo3Attach> {
+        let mut attach_opts = ContainerAttachOpts::builder();
+
+        bo_setter!(stdin, attach_opts);
+        bo_setter!(stdout, attach_opts);
+        bo_setter!(stderr, attach_opts);
+        bo_setter!(logs, attach_opts);
+        bo_setter!(stream, attach_opts);
+
+        let rv = py.allow_threads(|| Pyo3Attach::open(&self.0, &attach_opts.build()));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+
+    fn copy_from(&self, py: Python<'_>, src: &str, dst: &str) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_copy_from(&self.0, src));
 
         match rv {
             Ok(rv) => {
@@ -774,13 +1327,12 @@ impl Pyo3Container {
         }
     }
 
-    fn copy_file_into(&self, src: &str, dst: &str) -> PyResult<()> {
-        let mut file = File::open(src).unwrap();
-        let mut bytes = Vec::new();
-        file.read_to_end(&mut bytes)
-            .expect("Cannot read file on the localhost.");
-
-        let rv = __container_copy_file_into(&self.0, dst, &bytes);
+    /// Upload the local file at `src` into the container at `dst`. `src` is
+    /// read with Tokio's async file I/O, so the read doesn't block the
+    /// executor thread; the whole file is still held in memory before the
+    /// upload starts, so memory use grows with the file's size.
+    fn copy_file_into(&self, py: Python<'_>, src: &str, dst: &str) -> PyResult<()> {
+        let rv = py.allow_threads(|| __container_copy_file_into(&self.0, dst, src));
 
         match rv {
             Ok(_rv) => Ok(()),
@@ -788,19 +1340,108 @@ impl Pyo3Container {
         }
     }
 
-    fn stat_file(&self, path: &str) -> Py<PyAny> {
-        let rv = __container_stat_file(&self.0, path).unwrap();
+    /// Download `path` out of the container as a tar archive and either
+    /// write it to `dst` verbatim or, if `dst` already exists as a
+    /// directory, unpack it there.
+    fn get_archive(&self, py: Python<'_>, path: &str, dst: &str) -> PyResult<()> {
+        let bytes = py
+            .allow_threads(|| __container_copy_from(&self.0, path))
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))?;
+        write_or_unpack_tar(&bytes, dst).map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))
+    }
+
+    /// Download `path` out of the container as a raw tar archive, without
+    /// unpacking it anywhere - the lower-level counterpart to
+    /// `get_archive`, for callers that want to inspect or redirect the
+    /// archive themselves instead of having it written to local disk.
+    fn get_archive_bytes<'py>(&self, py: Python<'py>, path: &str) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = py
+            .allow_threads(|| __container_copy_from(&self.0, path))
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Upload `local_tar_or_dir` into the container at `path`, one file at
+    /// a time through the same archive endpoint `copy_file_into` already
+    /// uses. `local_tar_or_dir` is read as a `.tar` archive if it names an
+    /// existing file, or walked recursively if it names a directory.
+    fn put_archive(&self, py: Python<'_>, path: &str, local_tar_or_dir: &str) -> PyResult<()> {
+        let entries = collect_archive_entries(local_tar_or_dir)
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))?;
+
+        let path = path.trim_end_matches('/');
+        for (relative_path, bytes) in entries {
+            let dst = format!("{path}/{relative_path}");
+            py.allow_threads(|| __container_copy_bytes_into(&self.0, &dst, &bytes))
+                .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))?;
+        }
+
+        Ok(())
+    }
+
+    /// Upload an already-built tar archive into the container at `path` -
+    /// the lower-level counterpart to `put_archive`, for callers that
+    /// already have tar bytes in hand rather than a local path to walk.
+    fn put_archive_bytes(&self, py: Python<'_>, path: &str, tar_bytes: &[u8]) -> PyResult<()> {
+        py.allow_threads(|| __container_copy_bytes_into(&self.0, path, tar_bytes))
+            .map_err(|e| exceptions::PySystemError::new_err(format!("{e}")))
+    }
+
+    fn stat_file(&self, py: Python<'_>, path: &str) -> Py<PyAny> {
+        let rv = py.allow_threads(|| __container_stat_file(&self.0, path)).unwrap();
         pythonize_this!(rv)
     }
 
-    fn commit(&self) -> PyResult<()> {
-        Err(exceptions::PyNotImplementedError::new_err(
-            "This method is not available yet.",
-        ))
+    /// Snapshot the container's current state into a new image (`POST
+    /// /commit`).
+    ///
+    /// Args:
+    ///     repo: Repository name for the committed image (e.g. "my-image")
+    ///     tag: Tag for the committed image (e.g. "latest")
+    ///     comment: Commit message
+    ///     author: Author metadata
+    ///     pause: Pause the container while the commit is taken
+    ///     changes: Dockerfile-style directives applied to the committed
+    ///         image (e.g. ["CMD [\"/bin/sh\"]", "EXPOSE 8080"])
+    ///
+    /// Returns:
+    ///     str: Id of the newly created image
+    #[pyo3(signature = (repo=None, tag=None, comment=None, author=None, pause=None, changes=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn commit(
+        &self,
+        py: Python<'_>,
+        repo: Option<&str>,
+        tag: Option<&str>,
+        comment: Option<&str>,
+        author: Option<&str>,
+        pause: Option<bool>,
+        changes: Option<&Bound<'_, PyList>>,
+    ) -> PyResult<Py<PyAny>> {
+        let mut commit_opts = ContainerCommitOpts::builder().container(self.0.id());
+
+        bo_setter!(repo, commit_opts);
+        bo_setter!(tag, commit_opts);
+        bo_setter!(comment, commit_opts);
+        bo_setter!(author, commit_opts);
+        bo_setter!(pause, commit_opts);
+
+        if let Some(changes) = changes {
+            let change_strings: Vec<String> = changes.extract().unwrap();
+            let changes: Vec<&str> = change_strings.iter().map(|s| s.as_str()).collect();
+            commit_opts = commit_opts.changes(changes);
+        }
+
+        let rv = py.allow_threads(|| __container_commit(&self.1, &commit_opts.build()));
+
+        match rv {
+            Ok(image_id) => Ok(pythonize_this!(image_id)),
+            Err(e) => Err(exceptions::PySystemError::new_err(format!("{e}"))),
+        }
     }
 
-    fn __repr__(&self) -> String {
-        let inspect = __container_inspect(&self.0);
+    fn __repr__(&self, py: Python<'_>) -> String {
+        let inspect = py.allow_threads(|| __container_inspect(&self.0));
         format!(
             "Container(id: {}, name: {}, status: {})",
             inspect.id.unwrap(),
@@ -812,120 +1453,945 @@ impl Pyo3Container {
     fn __string__(&self) -> String {
         self.__repr__()
     }
+
+    /// Create a container from a [`ContainerSpec`] - a Python `dict`, a
+    /// JSON string, or a YAML string - instead of `Containers.create`'s
+    /// long positional argument list. Opens its own connection to the
+    /// daemon (there's no existing `Container`/`Containers` instance to
+    /// create through yet). See [`Pyo3Container::to_spec`] for the
+    /// reverse direction.
+    #[staticmethod]
+    fn from_spec(py: Python<'_>, spec: &Bound<'_, PyAny>) -> PyResult<Pyo3Container> {
+        let spec = ContainerSpec::from_py(spec)?;
+
+        let docker = Docker::new(crate::SYSTEM_DEFAULT_URI).map_err(DockerPyo3Error::from)?;
+        let containers = Pyo3Docker(docker, get_runtime()).containers();
+
+        let command_list = spec.command.as_ref().map(|c| PyList::new(py, c));
+        let env_pairs: Vec<String> = spec.env.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        let env_list = (!env_pairs.is_empty()).then(|| PyList::new(py, &env_pairs));
+        let labels_dict = PyDict::new(py);
+        for (key, value) in &spec.labels {
+            labels_dict.set_item(key, value)?;
+        }
+        let volumes_list = (!spec.volumes.is_empty()).then(|| PyList::new(py, &spec.volumes));
+
+        // Translate "published:target" port strings into `expose` entries
+        // (fixed host mapping) or, for a bare container port, `publish`
+        // entries (daemon picks the host port) - same translation
+        // `Pyo3Stack::deploy_service` does for a service's `ports`.
+        let mut expose_dicts = Vec::new();
+        let mut publish_dicts = Vec::new();
+        for port in &spec.ports {
+            if let Some((published, target)) = port.split_once(':') {
+                if let (Ok(published), Ok(target)) = (published.parse::<u32>(), target.parse::<u32>()) {
+                    let dict = PyDict::new(py);
+                    dict.set_item("srcport", target)?;
+                    dict.set_item("hostport", published)?;
+                    expose_dicts.push(dict);
+                }
+            } else if let Ok(target) = port.parse::<u32>() {
+                let dict = PyDict::new(py);
+                dict.set_item("port", target)?;
+                publish_dicts.push(dict);
+            }
+        }
+        let expose_list = (!expose_dicts.is_empty()).then(|| PyList::new(py, &expose_dicts));
+        let publish_list = (!publish_dicts.is_empty()).then(|| PyList::new(py, &publish_dicts));
+
+        containers.create(
+            py,
+            &spec.image,                // image
+            None,                       // attach_stderr
+            None,                       // attach_stdin
+            None,                       // attach_stdout
+            None,                       // auto_remove
+            None,                       // blkio_weight
+            None,                       // capabilities
+            None,                       // cap_drop
+            command_list.as_ref(),      // command
+            None,                       // cpu_period
+            None,                       // cpu_quota
+            None,                       // cpu_shares
+            None,                       // cpus
+            None,                       // devices
+            None,                       // dns
+            None,                       // dns_search
+            None,                       // entrypoint
+            env_list.as_ref(),          // env
+            expose_list.as_ref(),       // expose
+            None,                       // extra_hosts
+            Some(&labels_dict),         // labels
+            None,                       // links
+            None,                       // log_driver
+            None,                       // memory
+            None,                       // memory_swap
+            None,                       // name
+            None,                       // nano_cpus
+            None,                       // network_mode
+            None,                       // oom_kill_disable
+            None,                       // pids_limit
+            None,                       // privileged
+            publish_list.as_ref(),      // publish
+            None,                       // publish_all_ports
+            None,                       // readonly_rootfs
+            None,                       // restart_policy
+            None,                       // security_options
+            None,                       // shm_size
+            None,                       // stop_signal
+            None,                       // stop_signal_num
+            None,                       // stop_timeout
+            None,                       // tty
+            None,                       // user
+            None,                       // userns_mode
+            volumes_list.as_ref(),      // volumes
+            None,                       // volumes_from
+            spec.working_dir.as_deref(), // working_dir
+            None,                       // resolve_host_paths
+        )
+    }
+
+    /// Snapshot this container's image, command, environment, labels,
+    /// working directory, bind-mounted volumes, and published ports into
+    /// a [`ContainerSpec`], returned to Python as a dict. The inverse of
+    /// [`Pyo3Container::from_spec`] - store the result as JSON/YAML, diff
+    /// it, and feed it back in later.
+    fn to_spec(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let ci = py
+            .allow_threads(|| __container_inspect_checked(&self.0))
+            .map_err(DockerPyo3Error::from)?;
+        let live =
+            serde_yaml::to_value(&ci).map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+
+        let get = |path: &[&str]| -> Option<&serde_yaml::Value> {
+            let mut cur = &live;
+            for key in path {
+                cur = cur.get(*key)?;
+            }
+            Some(cur)
+        };
+
+        let image = get(&["Config", "Image"]).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+
+        let command = get(&["Config", "Cmd"]).and_then(|v| v.as_sequence()).map(|seq| {
+            seq.iter().filter_map(|v| v.as_str().map(String::from)).collect::<Vec<_>>()
+        });
+
+        let env = get(&["Config", "Env"])
+            .and_then(|v| v.as_sequence())
+            .map(|seq| {
+                seq.iter()
+                    .filter_map(|v| v.as_str())
+                    .filter_map(|entry| entry.split_once('='))
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let labels = get(&["Config", "Labels"])
+            .and_then(|v| v.as_mapping())
+            .map(|m| {
+                m.iter()
+                    .filter_map(|(k, v)| Some((k.as_str()?.to_string(), v.as_str()?.to_string())))
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let working_dir = get(&["Config", "WorkingDir"])
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(String::from);
+
+        let volumes = get(&["HostConfig", "Binds"])
+            .and_then(|v| v.as_sequence())
+            .map(|seq| seq.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+
+        let ports = get(&["HostConfig", "PortBindings"])
+            .and_then(|v| v.as_mapping())
+            .map(|m| {
+                m.iter()
+                    .filter_map(|(k, v)| {
+                        let container_port = k.as_str()?.split('/').next()?;
+                        let host_port = v.as_sequence()?.first()?.get("HostPort")?.as_str()?;
+                        Some(format!("{host_port}:{container_port}"))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let spec = ContainerSpec { image, command, env, volumes, ports, labels, working_dir };
+        Ok(pythonize_this!(spec))
+    }
+}
+
+/// Handle to an exec session created by `Container.create_exec(...)` but
+/// not yet started, letting callers start it, read its output, and then
+/// inspect it for the exit code - something a one-shot `Container.exec()`
+/// call can't give back.
+#[pyclass(name = "Exec")]
+pub struct Pyo3Exec(Exec, bool);
+
+#[pymethods]
+impl Pyo3Exec {
+    /// Start the exec session and wait for it to finish, returning its
+    /// captured output. `Exec.inspect()` should be called afterward for
+    /// the exit code.
+    ///
+    /// Returns:
+    ///     dict: {"stdout": str, "stderr": str}
+    fn start(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __exec_start(&self.0, self.1));
+
+        match rv {
+            Ok(result) => Ok(pythonize_this!(result)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Inspect the exec session's current state.
+    ///
+    /// Returns:
+    ///     dict: Exec metadata, including whether it's still running,
+    ///         its exit code, and its pid
+    fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __exec_inspect(&self.0));
+
+        match rv {
+            Ok(result) => Ok(pythonize_this!(result)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Resize the exec session's pseudo-TTY, for interactive use.
+    ///
+    /// Args:
+    ///     width: Terminal width, in characters
+    ///     height: Terminal height, in characters
+    fn resize(&self, py: Python<'_>, width: u64, height: u64) -> PyResult<()> {
+        let rv = py.allow_threads(|| __exec_resize(&self.0, width, height));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
 }
 
-#[tokio::main]
-async fn __container_inspect(container: &Container) -> ContainerInspect200Response {
-    let c = container.inspect().await;
-    c.unwrap()
+/// A live, bidirectional handle to a container's attached main-process
+/// streams, returned by `Container.attach(...)`. Reading demuxes
+/// `TtyChunk::StdOut`/`StdErr` into `{"stream": ..., "data": ...}` dicts one
+/// chunk at a time (each `next(...)` blocks on the shared runtime, with the
+/// GIL released, until a chunk arrives); `write_stdin(...)` feeds bytes
+/// back into the same connection. `close()` (or dropping the handle)
+/// cancels it.
+#[pyclass(name = "Attach")]
+pub struct Pyo3Attach {
+    stream: Option<BoxStream<'static, Result<TtyChunk, docker_api::Error>>>,
+    sink: Option<std::pin::Pin<Box<dyn futures_util::Sink<Vec<u8>, Error = docker_api::Error> + Send>>>,
 }
 
-#[tokio::main]
-async fn __container_logs(container: &Container, log_opts: &LogsOpts) -> String {
-    let log_stream = container.logs(log_opts);
+impl Pyo3Attach {
+    fn open(container: &Container, opts: &ContainerAttachOpts) -> Result<Self, docker_api::Error> {
+        get_runtime().block_on(async {
+            let (sink, stream) = container.attach(opts).await?.split();
+            Ok(Pyo3Attach {
+                stream: Some(stream.boxed()),
+                sink: Some(Box::pin(sink)),
+            })
+        })
+    }
 
-    let log = log_stream
-        .map(|chunk| match chunk {
-            Ok(chunk) => chunk.to_vec(),
-            Err(e) => {
-                eprintln!("Error: {e}");
-                vec![]
+    fn next_chunk(&mut self) -> PyResult<Option<TtyChunk>> {
+        get_runtime().block_on(async {
+            let Some(stream) = self.stream.as_mut() else {
+                return Ok(None);
+            };
+
+            match stream.next().await {
+                Some(Ok(chunk)) => Ok(Some(chunk)),
+                Some(Err(e)) => Err(DockerPyo3Error::from(e)),
+                None => {
+                    self.stream = None;
+                    Ok(None)
+                }
             }
         })
-        .collect::<Vec<_>>()
-        .await
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>();
+    }
+}
+
+#[pymethods]
+impl Pyo3Attach {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let chunk = py.allow_threads(|| self.next_chunk())?;
+
+        Ok(chunk.map(|chunk| {
+            let (stream_name, data) = match chunk {
+                TtyChunk::StdOut(bytes) => ("stdout", bytes),
+                TtyChunk::StdErr(bytes) => ("stderr", bytes),
+                TtyChunk::StdIn(bytes) => ("stdin", bytes),
+            };
+
+            let result = PyDict::new(py);
+            result.set_item("stream", stream_name).unwrap();
+            result.set_item("data", PyBytes::new(py, &data)).unwrap();
+            result.into()
+        }))
+    }
+
+    /// Write `data` to the container's stdin.
+    fn write_stdin(&mut self, py: Python<'_>, data: &[u8]) -> PyResult<()> {
+        let data = data.to_vec();
+        py.allow_threads(|| {
+            get_runtime().block_on(async {
+                let Some(sink) = self.sink.as_mut() else {
+                    return Err(DockerPyo3Error::InvalidParameter(
+                        "attach stream's stdin is not open".to_string(),
+                    ));
+                };
+                sink.send(data).await.map_err(DockerPyo3Error::from)
+            })
+        })?;
+        Ok(())
+    }
+
+    /// Cancel the underlying connection; a subsequent `next()` simply ends
+    /// the iteration instead of raising.
+    fn close(&mut self) {
+        self.stream = None;
+        self.sink = None;
+    }
+}
+
+/// A live iterator over `/containers/{id}/logs`, returned by
+/// `Container.logs_stream(...)`. Framed the same way `events::Pyo3EventStream`
+/// frames `/events`, except each chunk is raw log bytes rather than a JSON
+/// object, so it's decoded and yielded as soon as it arrives with no
+/// buffering beyond that - each call to `next(...)` blocks (on the shared
+/// runtime, with the GIL released) only until the next chunk arrives,
+/// keeping memory bounded even with `follow=True` against a live
+/// container; `close()` (or dropping the iterator) cancels the connection.
+#[pyclass(name = "LogStream")]
+pub struct Pyo3LogStream {
+    stream: Option<BoxStream<'static, Result<Vec<u8>, docker_api::Error>>>,
+}
+
+impl Pyo3LogStream {
+    fn open(docker: Docker, path: String) -> PyResult<Self> {
+        let stream = get_runtime()
+            .block_on(docker.stream_get(path))
+            .map_err(DockerPyo3Error::from)?;
+
+        Ok(Pyo3LogStream {
+            stream: Some(stream.boxed()),
+        })
+    }
 
-    format!("{}", String::from_utf8_lossy(&log))
+    fn next_chunk(&mut self) -> PyResult<Option<Vec<u8>>> {
+        get_runtime().block_on(async {
+            let Some(stream) = self.stream.as_mut() else {
+                return Ok(None);
+            };
+
+            match stream.next().await {
+                Some(Ok(chunk)) => Ok(Some(chunk)),
+                Some(Err(e)) => Err(DockerPyo3Error::from(e)),
+                None => {
+                    self.stream = None;
+                    Ok(None)
+                }
+            }
+        })
+    }
 }
 
-#[tokio::main]
-async fn __container_delete(container: &Container) -> Result<String, docker_api::Error> {
-    container.delete().await
+#[pymethods]
+impl Pyo3LogStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<String>> {
+        let chunk = py.allow_threads(|| self.next_chunk())?;
+        Ok(chunk.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    /// Cancel the underlying connection; a subsequent `next()` simply ends
+    /// the iteration instead of raising.
+    fn close(&mut self) {
+        self.stream = None;
+    }
 }
 
-#[tokio::main]
-async fn __container_start(container: &Container) -> Result<(), docker_api::Error> {
-    container.start().await
+/// A live iterator over `/containers/{id}/stats?stream=true`, returned by
+/// `Container.stats(stream=True)`. Framed the same way
+/// `events::Pyo3EventStream` frames `/events` - each call to `next(...)`
+/// blocks (on the shared runtime, with the GIL released) until a whole
+/// JSON snapshot has been read off the connection; `close()` (or dropping
+/// the iterator) cancels it.
+#[pyclass(name = "ContainerStats")]
+pub struct Pyo3ContainerStats {
+    stream: Option<BoxStream<'static, Result<Vec<u8>, docker_api::Error>>>,
+    buffer: Vec<u8>,
 }
 
-#[tokio::main]
-async fn __container_stop(
+impl Pyo3ContainerStats {
+    fn open(docker: Docker, container_id: String) -> PyResult<Self> {
+        let path = format!("/containers/{container_id}/stats?stream=true");
+        let stream = get_runtime()
+            .block_on(docker.stream_get(path))
+            .map_err(DockerPyo3Error::from)?;
+
+        Ok(Pyo3ContainerStats {
+            stream: Some(stream.boxed()),
+            buffer: Vec::new(),
+        })
+    }
+
+    fn next_snapshot(&mut self) -> PyResult<Option<serde_yaml::Value>> {
+        get_runtime().block_on(async {
+            loop {
+                if let Some(len) = events::take_complete_object(&self.buffer) {
+                    let object_bytes: Vec<u8> = self.buffer.drain(..len).collect();
+                    let value: serde_yaml::Value = serde_yaml::from_slice(&object_bytes)
+                        .map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+                    return Ok(Some(value));
+                }
+
+                let Some(stream) = self.stream.as_mut() else {
+                    return Ok(None);
+                };
+
+                match stream.next().await {
+                    Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                    Some(Err(e)) => return Err(DockerPyo3Error::from(e)),
+                    None => {
+                        self.stream = None;
+                        return Ok(None);
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[pymethods]
+impl Pyo3ContainerStats {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let snapshot = py.allow_threads(|| self.next_snapshot())?;
+        Ok(snapshot.map(|value| pythonize_this!(augment_stats(value))))
+    }
+
+    /// Cancel the underlying connection; a subsequent `next()` simply ends
+    /// the iteration instead of raising.
+    fn close(&mut self) {
+        self.stream = None;
+    }
+}
+
+fn __container_stats_once(docker: &Docker, path: &str) -> Result<serde_yaml::Value, docker_api::Error> {
+    get_runtime().block_on(async {
+        let mut stream = docker.stream_get(path.to_string());
+        let mut buffer: Vec<u8> = Vec::new();
+
+        loop {
+            if let Some(len) = events::take_complete_object(&buffer) {
+                let object_bytes: Vec<u8> = buffer.drain(..len).collect();
+                return Ok(serde_yaml::from_slice(&object_bytes)
+                    .unwrap_or(serde_yaml::Value::Null));
+            }
+
+            match stream.next().await {
+                Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => return Err(e),
+                None => return Ok(serde_yaml::Value::Null),
+            }
+        }
+    })
+}
+
+/// Add `cpu_percent`, `mem_usage`, `mem_limit`, and `mem_percent` to a raw
+/// `/stats` snapshot, computed with the same formula the Docker CLI uses -
+/// see [`Pyo3Container::stats`].
+fn augment_stats(mut value: serde_yaml::Value) -> serde_yaml::Value {
+    let get_u64 = |path: &[&str]| -> Option<u64> {
+        let mut cur = &value;
+        for key in path {
+            cur = cur.get(*key)?;
+        }
+        cur.as_u64()
+    };
+
+    let cpu_percent = match (
+        get_u64(&["cpu_stats", "cpu_usage", "total_usage"]),
+        get_u64(&["precpu_stats", "cpu_usage", "total_usage"]),
+        get_u64(&["cpu_stats", "system_cpu_usage"]),
+        get_u64(&["precpu_stats", "system_cpu_usage"]),
+    ) {
+        (Some(cpu_usage), Some(precpu_usage), Some(system_usage), Some(presystem_usage)) => {
+            let cpu_delta = cpu_usage as i64 - precpu_usage as i64;
+            let system_delta = system_usage as i64 - presystem_usage as i64;
+            if cpu_delta > 0 && system_delta > 0 {
+                let online_cpus = get_u64(&["cpu_stats", "online_cpus"]).unwrap_or(1).max(1);
+                (cpu_delta as f64 / system_delta as f64) * online_cpus as f64 * 100.0
+            } else {
+                0.0
+            }
+        }
+        _ => 0.0,
+    };
+
+    let mem_usage = get_u64(&["memory_stats", "usage"]).unwrap_or(0);
+    let mem_limit = get_u64(&["memory_stats", "limit"]).unwrap_or(0);
+    let mem_percent = if mem_limit > 0 {
+        (mem_usage as f64 / mem_limit as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let (net_rx_bytes, net_tx_bytes) = match value.get("networks").and_then(|n| n.as_mapping()) {
+        Some(interfaces) => interfaces.values().fold((0u64, 0u64), |(rx, tx), iface| {
+            (
+                rx + iface.get("rx_bytes").and_then(|v| v.as_u64()).unwrap_or(0),
+                tx + iface.get("tx_bytes").and_then(|v| v.as_u64()).unwrap_or(0),
+            )
+        }),
+        None => (0, 0),
+    };
+
+    let (blkio_read_bytes, blkio_write_bytes) = match get_u64_series(
+        &value,
+        &["blkio_stats", "io_service_bytes_recursive"],
+    ) {
+        Some(entries) => entries.iter().fold((0u64, 0u64), |(read, write), entry| {
+            let op = entry.get("op").and_then(|v| v.as_str()).unwrap_or("");
+            let amount = entry.get("value").and_then(|v| v.as_u64()).unwrap_or(0);
+            match op {
+                "Read" => (read + amount, write),
+                "Write" => (read, write + amount),
+                _ => (read, write),
+            }
+        }),
+        None => (0, 0),
+    };
+
+    if let serde_yaml::Value::Mapping(ref mut map) = value {
+        map.insert("cpu_percent".into(), cpu_percent.into());
+        map.insert("mem_usage".into(), mem_usage.into());
+        map.insert("mem_limit".into(), mem_limit.into());
+        map.insert("mem_percent".into(), mem_percent.into());
+        map.insert("net_rx_bytes".into(), net_rx_bytes.into());
+        map.insert("net_tx_bytes".into(), net_tx_bytes.into());
+        map.insert("blkio_read_bytes".into(), blkio_read_bytes.into());
+        map.insert("blkio_write_bytes".into(), blkio_write_bytes.into());
+    }
+
+    value
+}
+
+/// Read the `Vec<Mapping>`-shaped stats field at `path` (e.g.
+/// `blkio_stats.io_service_bytes_recursive`), if present, so
+/// [`augment_stats`] can sum per-device entries without caring whether the
+/// daemon omitted the field entirely (seen on cgroup v2 hosts for some
+/// blkio counters).
+fn get_u64_series<'a>(
+    value: &'a serde_yaml::Value,
+    path: &[&str],
+) -> Option<&'a [serde_yaml::Value]> {
+    let mut cur = value;
+    for key in path {
+        cur = cur.get(*key)?;
+    }
+    cur.as_sequence().map(|seq| seq.as_slice())
+}
+
+fn __container_inspect(container: &Container) -> ContainerInspect200Response {
+    get_runtime().block_on(async {
+        let c = container.inspect().await;
+        c.unwrap()
+    })
+}
+
+/// Encode the logs-endpoint query string for `GET /containers/{id}/logs`
+/// by hand, mirroring `events::build_events_path` - `Pyo3LogStream` reads
+/// chunks lazily off `docker.stream_get`, so it needs a raw path rather
+/// than a `LogsOpts` value to hand to `container.logs(opts)` in one shot.
+#[allow(clippy::too_many_arguments)]
+fn build_logs_path(
+    container_id: &str,
+    stdout: Option<bool>,
+    stderr: Option<bool>,
+    timestamps: Option<bool>,
+    n_lines: Option<usize>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    follow: Option<bool>,
+) -> String {
+    let mut query = vec![
+        format!("follow={}", follow.unwrap_or(false)),
+        format!("stdout={}", stdout.unwrap_or(true)),
+        format!("stderr={}", stderr.unwrap_or(true)),
+        format!("timestamps={}", timestamps.unwrap_or(false)),
+    ];
+
+    if let Some(n_lines) = n_lines {
+        query.push(format!("tail={n_lines}"));
+    }
+    if let Some(since) = since {
+        query.push(format!("since={}", since.timestamp()));
+    }
+    if let Some(until) = until {
+        query.push(format!("until={}", until.timestamp()));
+    }
+
+    format!("/containers/{container_id}/logs?{}", query.join("&"))
+}
+
+fn __container_logs(container: &Container, log_opts: &LogsOpts) -> String {
+    get_runtime().block_on(async {
+        let log_stream = container.logs(log_opts);
+
+        let log = log_stream
+            .map(|chunk| match chunk {
+                Ok(chunk) => chunk.to_vec(),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    vec![]
+                }
+            })
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        format!("{}", String::from_utf8_lossy(&log))
+    })
+}
+
+fn __container_delete(container: &Container) -> Result<String, docker_api::Error> {
+    get_runtime().block_on(container.delete())
+}
+
+fn __container_start(container: &Container) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(container.start())
+}
+
+fn __container_stop(
     container: &Container,
     wait: Option<std::time::Duration>,
 ) -> Result<(), docker_api::Error> {
-    container.stop(wait).await
+    get_runtime().block_on(container.stop(wait))
 }
 
-#[tokio::main]
-async fn __container_restart(
+fn __container_restart(
     container: &Container,
     wait: Option<std::time::Duration>,
 ) -> Result<(), docker_api::Error> {
-    container.restart(wait).await
+    get_runtime().block_on(container.restart(wait))
 }
 
-#[tokio::main]
-async fn __container_kill(
-    container: &Container,
-    signal: Option<&str>,
-) -> Result<(), docker_api::Error> {
-    container.kill(signal).await
+fn __container_kill(container: &Container, signal: Option<&str>) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(container.kill(signal))
 }
 
-#[tokio::main]
-async fn __container_rename(container: &Container, name: &str) -> Result<(), docker_api::Error> {
-    container.rename(name).await
+fn __container_rename(container: &Container, name: &str) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(container.rename(name))
 }
 
-#[tokio::main]
-async fn __container_pause(container: &Container) -> Result<(), docker_api::Error> {
-    container.pause().await
+fn __container_commit(
+    docker: &Docker,
+    commit_opts: &ContainerCommitOpts,
+) -> Result<String, docker_api::Error> {
+    get_runtime().block_on(async { Ok(Images::new(docker.clone()).commit(commit_opts).await?.id) })
 }
 
-#[tokio::main]
-async fn __container_unpause(container: &Container) -> Result<(), docker_api::Error> {
-    container.unpause().await
+fn __container_pause(container: &Container) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(container.pause())
 }
 
-#[tokio::main]
-async fn __container_wait(
-    container: &Container,
-) -> Result<ContainerWaitResponse, docker_api::Error> {
-    container.wait().await
+fn __container_unpause(container: &Container) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(container.unpause())
 }
 
-#[tokio::main]
-async fn __container_exec(
-    container: &Container,
-    exec_opts: ExecCreateOpts,
-) -> Option<Result<TtyChunk, docker_api::conn::Error>> {
-    container.exec(&exec_opts).next().await
+/// Poll `/containers/{id}/wait?condition=...` the same way
+/// `__container_stats_once` frames `/stats` - `Container::wait()` doesn't
+/// expose the condition query parameter - and race it against `timeout`
+/// if one was given. `Ok(None)` signals the timeout elapsed first.
+pub(crate) fn __container_wait(
+    docker: &Docker,
+    container_id: &str,
+    condition: Option<&str>,
+    timeout: Option<std::time::Duration>,
+) -> Result<Option<serde_yaml::Value>, docker_api::Error> {
+    let path = match condition {
+        Some(condition) => format!("/containers/{container_id}/wait?condition={condition}"),
+        None => format!("/containers/{container_id}/wait"),
+    };
+
+    get_runtime().block_on(async {
+        let wait = async {
+            let mut stream = docker.stream_get(path.clone());
+            let mut buffer: Vec<u8> = Vec::new();
+
+            loop {
+                if let Some(len) = events::take_complete_object(&buffer) {
+                    let object_bytes: Vec<u8> = buffer.drain(..len).collect();
+                    return Ok(serde_yaml::from_slice(&object_bytes).unwrap_or(serde_yaml::Value::Null));
+                }
+
+                match stream.next().await {
+                    Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                    Some(Err(e)) => return Err(e),
+                    None => return Ok(serde_yaml::Value::Null),
+                }
+            }
+        };
+
+        match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, wait).await {
+                Ok(result) => result.map(Some),
+                Err(_) => Ok(None),
+            },
+            None => wait.await.map(Some),
+        }
+    })
 }
 
-#[tokio::main]
-async fn __container_copy_from(
-    container: &Container,
-    path: &str,
-) -> Result<Vec<u8>, docker_api::Error> {
-    container.copy_from(path).try_concat().await
+/// Result of [`Pyo3Container::exec`]: the demuxed stdout/stderr captured
+/// while the command ran, plus the exit code read back from `exec inspect`
+/// once the command has finished.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExecResult {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i64>,
+}
+
+/// Result of [`Pyo3Exec::start`]: just the demuxed stdout/stderr, since the
+/// exit code isn't known until the caller inspects the session separately.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExecOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// A plain, serializable snapshot of the `Containers.create` parameters
+/// run specs actually vary most - image, command, environment, volumes,
+/// ports, labels, and working directory. Round-trips through
+/// [`Pyo3Container::from_spec`]/[`Pyo3Container::to_spec`], so a spec can
+/// be stored as JSON/YAML, diffed, and fed back in instead of threading
+/// positional `create()` arguments through by hand.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ContainerSpec {
+    pub image: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub volumes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ports: Vec<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+
+impl ContainerSpec {
+    /// Accept either a Python `dict` or a JSON/YAML string. `serde_yaml`
+    /// parses both shapes of text, since JSON is a structural subset of
+    /// YAML and `serde_json` isn't a dependency of this crate (see the
+    /// note in error.rs).
+    fn from_py(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(text) = value.extract::<String>() {
+            serde_yaml::from_str(&text)
+                .map_err(|e| exceptions::PyValueError::new_err(format!("invalid container spec: {e}")))
+        } else {
+            depythonize(value)
+                .map_err(|e| exceptions::PyValueError::new_err(format!("invalid container spec: {e}")))
+        }
+    }
+}
+
+fn __container_exec(
+    docker: &Docker,
+    container_id: &str,
+    exec_opts: &ExecCreateOpts,
+    tty: bool,
+) -> Result<ExecResult, docker_api::Error> {
+    get_runtime().block_on(async {
+        let exec = Exec::create(docker, container_id, exec_opts).await?;
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let mut stream = exec.start();
+        while let Some(chunk) = stream.next().await {
+            match chunk? {
+                TtyChunk::StdOut(bytes) => stdout.extend(bytes),
+                // A tty exec's output isn't multiplexed, so the daemon's
+                // raw bytes can't be reliably sorted into stdout/stderr -
+                // fold it all into stdout rather than risk splitting one
+                // stream's output across both.
+                TtyChunk::StdErr(bytes) if tty => stdout.extend(bytes),
+                TtyChunk::StdErr(bytes) => stderr.extend(bytes),
+                TtyChunk::StdIn(_) => {}
+            }
+        }
+        drop(stream);
+
+        let exit_code = exec.inspect().await?.exit_code;
+
+        Ok(ExecResult {
+            stdout: String::from_utf8_lossy(&stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&stderr).into_owned(),
+            exit_code,
+        })
+    })
+}
+
+fn __exec_create(
+    docker: &Docker,
+    container_id: &str,
+    exec_opts: &ExecCreateOpts,
+) -> Result<Exec, docker_api::Error> {
+    get_runtime().block_on(Exec::create(docker, container_id, exec_opts))
+}
+
+fn __exec_start(exec: &Exec, tty: bool) -> Result<ExecOutput, docker_api::Error> {
+    get_runtime().block_on(async {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let mut stream = exec.start();
+        while let Some(chunk) = stream.next().await {
+            match chunk? {
+                TtyChunk::StdOut(bytes) => stdout.extend(bytes),
+                TtyChunk::StdErr(bytes) if tty => stdout.extend(bytes),
+                TtyChunk::StdErr(bytes) => stderr.extend(bytes),
+                TtyChunk::StdIn(_) => {}
+            }
+        }
+        drop(stream);
+
+        Ok(ExecOutput {
+            stdout: String::from_utf8_lossy(&stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&stderr).into_owned(),
+        })
+    })
 }
 
-#[tokio::main]
-async fn __container_copy_file_into(
+fn __exec_inspect(exec: &Exec) -> Result<impl serde::Serialize, docker_api::Error> {
+    get_runtime().block_on(exec.inspect())
+}
+
+fn __exec_resize(exec: &Exec, width: u64, height: u64) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(exec.resize(width, height))
+}
+
+fn __container_copy_from(container: &Container, path: &str) -> Result<Vec<u8>, docker_api::Error> {
+    get_runtime().block_on(container.copy_from(path).try_concat())
+}
+
+fn __container_copy_bytes_into(
     container: &Container,
     dst: &str,
-    bytes: &Vec<u8>,
+    bytes: &[u8],
 ) -> Result<(), docker_api::Error> {
-    container.copy_file_into(dst, bytes).await
+    get_runtime().block_on(container.copy_file_into(dst, bytes))
 }
 
-#[tokio::main]
-async fn __container_stat_file(
+/// Read `src` off the local disk with Tokio's async file I/O (so the read
+/// doesn't block the executor thread the way a plain `std::fs::read`
+/// would) and upload it, the same way `__container_copy_bytes_into` does
+/// once the bytes are already in hand.
+fn __container_copy_file_into(
     container: &Container,
+    dst: &str,
     src: &str,
-) -> Result<String, docker_api::Error> {
-    container.stat_file(src).await
+) -> Result<(), DockerPyo3Error> {
+    get_runtime().block_on(async {
+        let bytes = tokio::fs::read(src).await?;
+        container.copy_file_into(dst, &bytes).await?;
+        Ok(())
+    })
+}
+
+fn __container_stat_file(container: &Container, src: &str) -> Result<String, docker_api::Error> {
+    get_runtime().block_on(container.stat_file(src))
+}
+
+fn __container_top(
+    container: &Container,
+    ps_args: Option<&str>,
+) -> Result<ContainerTopResponse, docker_api::Error> {
+    get_runtime().block_on(container.top(ps_args))
+}
+
+fn __container_export(container: &Container) -> Result<Vec<u8>, docker_api::Error> {
+    get_runtime().block_on(container.export().try_concat())
+}
+
+/// Write a tar byte stream to `dst`: unpacked in place if `dst` already
+/// exists as a directory, otherwise written verbatim as a single file -
+/// shared by `Pyo3Container::get_archive` and `Pyo3Container::export`.
+fn write_or_unpack_tar(bytes: &[u8], dst: &str) -> std::io::Result<()> {
+    if std::path::Path::new(dst).is_dir() {
+        Archive::new(bytes).unpack(dst)
+    } else {
+        std::fs::write(dst, bytes)
+    }
+}
+
+/// Read `src` into a flat list of `(relative path, file contents)` pairs:
+/// the entries of a `.tar` file if `src` names one, or every regular file
+/// under `src` found by walking it recursively if `src` names a
+/// directory. Used by `Pyo3Container::put_archive` to upload either shape
+/// one file at a time through the existing `copy_file_into` endpoint.
+fn collect_archive_entries(src: &str) -> std::io::Result<Vec<(String, Vec<u8>)>> {
+    let src_path = std::path::Path::new(src);
+    let mut entries = Vec::new();
+
+    if src_path.is_dir() {
+        collect_dir_entries(src_path, src_path, &mut entries)?;
+    } else {
+        let mut archive = Archive::new(File::open(src_path)?);
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let relative_path = entry.path()?.to_string_lossy().into_owned();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+            entries.push((relative_path, bytes));
+        }
+    }
+
+    Ok(entries)
+}
+
+fn collect_dir_entries(
+    root: &std::path::Path,
+    dir: &std::path::Path,
+    entries: &mut Vec<(String, Vec<u8>)>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_dir_entries(root, &path, entries)?;
+        } else {
+            let relative_path = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            entries.push((relative_path, std::fs::read(&path)?));
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,81 @@
+//! Docker-in-Docker awareness: detect whether this process is itself
+//! running inside a container, and translate bind-mount paths that are
+//! relative to *this* container's filesystem into the paths the real
+//! host Docker daemon needs to see. Without this, a `containers.create()`
+//! call made from code running inside a CI container would hand the
+//! daemon a path that doesn't exist on the host, and the bind mount would
+//! silently come up empty - following pre-commit's `_is_in_docker`/
+//! host-path-resolution logic for the same problem.
+
+use docker_api::Docker;
+
+use crate::error::DockerPyo3Error;
+use crate::get_runtime;
+
+/// True if this process appears to be running inside a container: either
+/// `/.dockerenv` exists, or `/proc/self/mountinfo` (falling back to
+/// `/proc/self/cgroup`) mentions a `docker`/`containerd` cgroup path.
+pub fn is_in_docker() -> bool {
+    if std::path::Path::new("/.dockerenv").exists() {
+        return true;
+    }
+
+    for path in ["/proc/self/mountinfo", "/proc/self/cgroup"] {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if contents.contains("/docker/") || contents.contains("docker-") || contents.contains("containerd") {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// This container's own ID, read off the `.../docker/<id>` (or
+/// `containerd`) cgroup path `/proc/self/cgroup` records - the same ID
+/// Docker uses as the container's hostname by default.
+fn own_container_id() -> Option<String> {
+    let contents = std::fs::read_to_string("/proc/self/cgroup").ok()?;
+    contents.lines().find_map(|line| {
+        let segment = line.rsplit('/').next()?;
+        (segment.len() >= 12 && segment.chars().all(|c| c.is_ascii_hexdigit())).then(|| segment.to_string())
+    })
+}
+
+/// Translate `inner_path` (a path as seen from inside *this* container) to
+/// the real host path the Docker daemon needs, by inspecting this
+/// container's own mounts and rewriting against whichever one's
+/// destination contains `inner_path`. Returns `inner_path` unchanged if
+/// this process's own container ID can't be determined, no mount covers
+/// it, or the daemon lookup fails - callers that need to know whether
+/// translation actually happened should check [`is_in_docker`] first.
+pub fn host_path_of(docker: &Docker, inner_path: &str) -> Result<String, DockerPyo3Error> {
+    let Some(container_id) = own_container_id() else {
+        return Ok(inner_path.to_string());
+    };
+
+    let Ok(inspect) = get_runtime().block_on(docker.containers().get(&container_id).inspect()) else {
+        return Ok(inner_path.to_string());
+    };
+    let live = serde_yaml::to_value(&inspect).map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+
+    let translated = live
+        .get("Mounts")
+        .and_then(|v| v.as_sequence())
+        .and_then(|mounts| {
+            mounts
+                .iter()
+                .filter_map(|mount| {
+                    let destination = mount.get("Destination")?.as_str()?;
+                    let source = mount.get("Source")?.as_str()?;
+                    Some((destination.to_string(), source.to_string()))
+                })
+                .filter(|(destination, _)| {
+                    inner_path == destination || inner_path.starts_with(&format!("{destination}/"))
+                })
+                .max_by_key(|(destination, _)| destination.len())
+        })
+        .map(|(destination, source)| format!("{source}{}", &inner_path[destination.len()..]));
+
+    Ok(translated.unwrap_or_else(|| inner_path.to_string()))
+}
@@ -1,4 +1,5 @@
-use pyo3::exceptions;
+use crate::exceptions;
+use pyo3::exceptions as py_exceptions;
 use pyo3::prelude::*;
 
 /// Custom error types for docker-pyo3
@@ -22,6 +23,9 @@ pub enum DockerPyo3Error {
     AlreadyExists(String),
     /// Operation not supported
     NotSupported(String),
+    /// A compose/stack configuration value this crate can't apply (e.g. a
+    /// key with no matching `create()` argument)
+    Configuration(String),
 }
 
 impl std::fmt::Display for DockerPyo3Error {
@@ -36,6 +40,7 @@ impl std::fmt::Display for DockerPyo3Error {
             DockerPyo3Error::NotFound(e) => write!(f, "Resource not found: {}", e),
             DockerPyo3Error::AlreadyExists(e) => write!(f, "Resource already exists: {}", e),
             DockerPyo3Error::NotSupported(e) => write!(f, "Operation not supported: {}", e),
+            DockerPyo3Error::Configuration(e) => write!(f, "Configuration error: {}", e),
         }
     }
 }
@@ -65,51 +70,87 @@ impl From<std::io::Error> for DockerPyo3Error {
 impl From<DockerPyo3Error> for PyErr {
     fn from(error: DockerPyo3Error) -> Self {
         match error {
-            DockerPyo3Error::DockerApi(e) => {
-                // Parse common Docker API errors and map to appropriate Python exceptions
-                let error_msg = e.to_string();
-                if error_msg.contains("404") || error_msg.contains("not found") || error_msg.contains("No such") {
-                    exceptions::PyFileNotFoundError::new_err(format!("Docker resource not found: {}", e))
-                } else if error_msg.contains("401") || error_msg.contains("403") {
-                    exceptions::PyPermissionError::new_err(format!("Docker permission denied: {}", e))
-                } else if error_msg.contains("409") || error_msg.contains("conflict") || error_msg.contains("already exists") {
-                    exceptions::PyFileExistsError::new_err(format!("Docker conflict: {}", e))
-                } else if error_msg.contains("connection") || error_msg.contains("timeout") || error_msg.contains("refused") || error_msg.contains("connect error") || error_msg.contains("Operation timed out") {
-                    exceptions::PyConnectionError::new_err(format!("Docker connection error: {}", e))
-                } else if error_msg.contains("400") || error_msg.contains("Bad Request") || error_msg.contains("invalid") {
-                    exceptions::PyValueError::new_err(format!("Docker invalid request: {}", e))
-                } else if error_msg.contains("500") || error_msg.contains("Internal Server Error") {
-                    exceptions::PyRuntimeError::new_err(format!("Docker server error: {}", e))
-                } else {
-                    exceptions::PyRuntimeError::new_err(format!("Docker error: {}", e))
-                }
-            },
+            DockerPyo3Error::DockerApi(e) => map_err(&e),
             DockerPyo3Error::InvalidParameter(msg) => {
-                exceptions::PyValueError::new_err(msg)
+                py_exceptions::PyValueError::new_err(msg)
             },
             DockerPyo3Error::Io(e) => {
-                exceptions::PyIOError::new_err(format!("I/O error: {}", e))
+                py_exceptions::PyIOError::new_err(format!("I/O error: {}", e))
             },
             DockerPyo3Error::Auth(msg) => {
-                exceptions::PyPermissionError::new_err(msg)
+                exceptions::APIError::new_err(msg)
             },
             DockerPyo3Error::Connection(msg) => {
-                exceptions::PyConnectionError::new_err(msg)
+                exceptions::ConnectionError::new_err(msg)
             },
             DockerPyo3Error::NotFound(msg) => {
-                exceptions::PyFileNotFoundError::new_err(msg)
+                exceptions::NotFound::new_err(msg)
             },
             DockerPyo3Error::AlreadyExists(msg) => {
-                exceptions::PyFileExistsError::new_err(msg)
+                exceptions::ConflictError::new_err(msg)
             },
             DockerPyo3Error::NotSupported(msg) => {
-                exceptions::PyNotImplementedError::new_err(msg)
+                py_exceptions::PyNotImplementedError::new_err(msg)
             },
             DockerPyo3Error::Serialization(msg) => {
-                exceptions::PyValueError::new_err(format!("Data serialization error: {}", msg))
+                py_exceptions::PyValueError::new_err(format!("Data serialization error: {}", msg))
             },
+            DockerPyo3Error::Configuration(msg) => {
+                exceptions::ConfigurationError::new_err(msg)
+            },
+        }
+    }
+}
+
+/// Pick the `exceptions` class a `docker_api::Error` should surface as,
+/// folding in whatever HTTP status code can be recovered from its message
+/// (the crate doesn't expose the status directly) so Python callers get
+/// both the class and the daemon's own status code/message to act on.
+pub fn map_err(error: &docker_api::Error) -> PyErr {
+    let message = error.to_string();
+    let status = extract_status_code(&message);
+    let detail = match status {
+        Some(code) => format!("{message} (status code: {code})"),
+        None => message.clone(),
+    };
+
+    if message.contains("404") || message.contains("not found") || message.contains("No such") {
+        exceptions::NotFound::new_err(detail)
+    } else if message.contains("409") || message.contains("conflict") || message.contains("already exists") {
+        exceptions::ConflictError::new_err(detail)
+    } else if message.contains("connection")
+        || message.contains("timeout")
+        || message.contains("refused")
+        || message.contains("connect error")
+        || message.contains("Operation timed out")
+    {
+        exceptions::ConnectionError::new_err(detail)
+    } else if status.is_some() {
+        exceptions::APIError::new_err(detail)
+    } else {
+        exceptions::DockerException::new_err(detail)
+    }
+}
+
+/// Best-effort extraction of a 3-digit HTTP status code out of an error
+/// message like `"... 404 Not Found ..."`, since `docker_api::Error`
+/// doesn't carry the status as a structured field.
+fn extract_status_code(message: &str) -> Option<u16> {
+    let bytes = message.as_bytes();
+    for (i, window) in bytes.windows(3).enumerate() {
+        if window.iter().all(u8::is_ascii_digit) {
+            let is_boundary_before = i == 0 || !bytes[i - 1].is_ascii_digit();
+            let is_boundary_after = i + 3 == bytes.len() || !bytes[i + 3].is_ascii_digit();
+            if is_boundary_before && is_boundary_after {
+                if let Ok(code) = message[i..i + 3].parse::<u16>() {
+                    if (100..600).contains(&code) {
+                        return Some(code);
+                    }
+                }
+            }
         }
     }
+    None
 }
 
 /// Convenient macro for creating errors
@@ -133,6 +174,9 @@ macro_rules! docker_error {
     (NotSupported, $msg:expr) => {
         crate::error::DockerPyo3Error::NotSupported($msg.to_string())
     };
+    (Configuration, $msg:expr) => {
+        crate::error::DockerPyo3Error::Configuration($msg.to_string())
+    };
 }
 
 /// Result type alias for convenience
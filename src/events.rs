@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use docker_api::Docker;
+use futures_util::stream::{BoxStream, StreamExt};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pythonize::pythonize;
+
+use crate::error::DockerPyo3Error;
+use crate::get_runtime;
+
+#[pymodule]
+pub fn events(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Pyo3EventStream>()?;
+    Ok(())
+}
+
+/// A live iterator over the daemon's `/events` stream, returned by
+/// `Docker.events(...)`. Each call to `next(...)` blocks (on the shared
+/// runtime, with the GIL released) until a whole JSON event object has
+/// been framed out of the underlying byte stream; `close()` (or dropping
+/// the iterator) cancels the connection.
+#[pyclass(name = "EventStream")]
+pub struct Pyo3EventStream {
+    stream: Option<BoxStream<'static, Result<Vec<u8>, docker_api::Error>>>,
+    buffer: Vec<u8>,
+}
+
+impl Pyo3EventStream {
+    pub fn open(
+        docker: Docker,
+        since: Option<&str>,
+        until: Option<&str>,
+        filters: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        let path = build_events_path(since, until, filters)?;
+
+        let stream = get_runtime()
+            .block_on(docker.stream_get(path))
+            .map_err(DockerPyo3Error::from)?;
+
+        Ok(Pyo3EventStream {
+            stream: Some(stream.boxed()),
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Block until a whole JSON object has been framed out of the stream,
+    /// pulling more bytes off the connection as needed. Returns `None` once
+    /// the stream has ended (or been `close()`d).
+    fn next_object(&mut self) -> PyResult<Option<serde_yaml::Value>> {
+        get_runtime().block_on(async {
+            loop {
+                if let Some(len) = take_complete_object(&self.buffer) {
+                    let object_bytes: Vec<u8> = self.buffer.drain(..len).collect();
+                    let value: serde_yaml::Value = serde_yaml::from_slice(&object_bytes)
+                        .map_err(|e| DockerPyo3Error::Serialization(e.to_string()))?;
+                    return Ok(Some(value));
+                }
+
+                let Some(stream) = self.stream.as_mut() else {
+                    return Ok(None);
+                };
+
+                match stream.next().await {
+                    Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                    Some(Err(e)) => return Err(DockerPyo3Error::from(e)),
+                    None => {
+                        self.stream = None;
+                        return Ok(None);
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[pymethods]
+impl Pyo3EventStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let object = py.allow_threads(|| self.next_object())?;
+        Ok(object.map(|value| pythonize_this!(value)))
+    }
+
+    /// Cancel the underlying connection; a subsequent `next()` simply ends
+    /// the iteration instead of raising.
+    fn close(&mut self) {
+        self.stream = None;
+    }
+}
+
+/// Encode `since`/`until`/`filters` into the query string `GET /events`
+/// expects, mirroring the `filters={"key":["value"]}` shape the daemon's
+/// other list endpoints (e.g. `/images/search`) already use in this crate.
+fn build_events_path(
+    since: Option<&str>,
+    until: Option<&str>,
+    filters: Option<&Bound<'_, PyDict>>,
+) -> PyResult<String> {
+    let mut query = Vec::new();
+
+    if let Some(since) = since {
+        query.push(format!("since={since}"));
+    }
+    if let Some(until) = until {
+        query.push(format!("until={until}"));
+    }
+    if let Some(filters) = filters {
+        let filters: HashMap<String, String> = filters.extract().map_err(|_| {
+            DockerPyo3Error::InvalidParameter(
+                "filters must be a dictionary of string keys and values".to_string(),
+            )
+        })?;
+        let encoded: Vec<String> = filters
+            .into_iter()
+            .map(|(key, value)| format!("\"{key}\":[\"{value}\"]"))
+            .collect();
+        query.push(format!("filters={{{}}}", encoded.join(",")));
+    }
+
+    if query.is_empty() {
+        Ok("/events".to_string())
+    } else {
+        Ok(format!("/events?{}", query.join("&")))
+    }
+}
+
+/// Scan `buffer` for a complete top-level JSON object (a balanced
+/// `{...}`, correctly skipping over braces inside quoted strings)
+/// starting at index 0. Returns how many leading bytes belong to that
+/// object if one is fully buffered yet, so a partial read at the end of
+/// `buffer` is left alone until more bytes arrive.
+///
+/// Shared with `container::Pyo3ContainerStats`, which frames the
+/// `/containers/{id}/stats` stream the same way this module frames
+/// `/events`.
+pub(crate) fn take_complete_object(buffer: &[u8]) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut started = false;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, &byte) in buffer.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'{' => {
+                depth += 1;
+                started = true;
+            }
+            b'}' => {
+                depth -= 1;
+                if started && depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
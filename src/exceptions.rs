@@ -0,0 +1,34 @@
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+/// Base class for every exception this crate raises.
+create_exception!(docker_pyo3, DockerException, PyException);
+
+/// The daemon rejected a request; carries the daemon's status code and message.
+create_exception!(docker_pyo3, APIError, DockerException);
+
+/// The daemon reported 404 for the requested container/image/network/volume.
+create_exception!(docker_pyo3, NotFound, APIError);
+
+/// The daemon reported 409 (e.g. a name already in use, or the resource is busy).
+create_exception!(docker_pyo3, ConflictError, APIError);
+
+/// The daemon could not be reached at all (socket/TCP connect failure, timeout).
+create_exception!(docker_pyo3, ConnectionError, DockerException);
+
+/// A compose/stack configuration value is invalid or not supported by this
+/// crate's container-creation API (e.g. a compose key that has no matching
+/// `create()` argument).
+create_exception!(docker_pyo3, ConfigurationError, DockerException);
+
+#[pymodule]
+pub fn exceptions(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("DockerException", py.get_type::<DockerException>())?;
+    m.add("APIError", py.get_type::<APIError>())?;
+    m.add("NotFound", py.get_type::<NotFound>())?;
+    m.add("ConflictError", py.get_type::<ConflictError>())?;
+    m.add("ConnectionError", py.get_type::<ConnectionError>())?;
+    m.add("ConfigurationError", py.get_type::<ConfigurationError>())?;
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::fs::OpenOptions;
+use std::fs::{File, OpenOptions};
 
 use crate::{get_runtime, Pyo3Docker};
 use crate::error::DockerPyo3Error;
@@ -7,27 +7,95 @@ use docker_api::models::{
     ImageSummary,
 };
 use docker_api::opts::{
-    ImageBuildOpts, ImageListOpts, ImagePushOpts, PullOpts, RegistryAuth, TagOpts,
+    ImageBuildOpts, ImageImportOpts, ImageListOpts, ImagePushOpts, PullOpts, RegistryAuth, TagOpts,
 };
 
-use docker_api::{Image, Images};
+use docker_api::{Docker, Image, Images};
 use futures_util::StreamExt;
 use pyo3::exceptions::{self, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict};
 use pythonize::pythonize;
-use std::{collections::HashMap, io::Write};
+use std::{collections::HashMap, io::Read, io::Write};
+use tar::Archive;
 
 #[pymodule]
 pub fn image(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Pyo3Images>()?;
     m.add_class::<Pyo3Image>()?;
+    m.add_class::<Pyo3AuthConfig>()?;
     Ok(())
 }
 
 #[derive(Debug)]
 #[pyclass(name = "Images")]
-pub struct Pyo3Images(pub Images);
+pub struct Pyo3Images(pub Images, Docker);
+
+/// Explicit registry credentials for `pull`/`push`, as an alternative to
+/// the `auth_password`/`auth_token` dicts. Set `identity_token` alone for
+/// a token login; otherwise `username`/`password` (and optionally `email`,
+/// `server_address`) are used.
+#[derive(Debug, Clone, Default)]
+#[pyclass(name = "AuthConfig")]
+pub struct Pyo3AuthConfig {
+    #[pyo3(get, set)]
+    pub username: Option<String>,
+    #[pyo3(get, set)]
+    pub password: Option<String>,
+    #[pyo3(get, set)]
+    pub email: Option<String>,
+    #[pyo3(get, set)]
+    pub server_address: Option<String>,
+    #[pyo3(get, set)]
+    pub identity_token: Option<String>,
+}
+
+#[pymethods]
+impl Pyo3AuthConfig {
+    #[new]
+    #[pyo3(signature = (username=None, password=None, email=None, server_address=None, identity_token=None))]
+    fn new(
+        username: Option<String>,
+        password: Option<String>,
+        email: Option<String>,
+        server_address: Option<String>,
+        identity_token: Option<String>,
+    ) -> Self {
+        Pyo3AuthConfig { username, password, email, server_address, identity_token }
+    }
+}
+
+impl Pyo3AuthConfig {
+    fn into_registry_auth(self) -> RegistryAuth {
+        if let Some(identity_token) = self.identity_token {
+            return RegistryAuth::token(identity_token);
+        }
+
+        let username = self.username;
+        let password = self.password;
+        let email = self.email;
+        let server_address = self.server_address;
+
+        let mut ra = RegistryAuth::builder();
+        bo_setter!(username, ra);
+        bo_setter!(password, ra);
+        bo_setter!(email, ra);
+        bo_setter!(server_address, ra);
+        ra.build()
+    }
+}
+
+/// A single entry from the Docker Hub / registry search endpoint
+/// (`GET /images/search`), not modeled in `docker_api::models` since the
+/// crate doesn't expose the search endpoint itself.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ImageSearchResult {
+    pub name: String,
+    pub description: String,
+    pub is_official: bool,
+    pub is_automated: bool,
+    pub star_count: u32,
+}
 
 #[derive(Debug)]
 #[pyclass(name = "Image")]
@@ -37,7 +105,7 @@ pub struct Pyo3Image(pub Image);
 impl Pyo3Images {
     #[new]
     pub fn new(docker: Pyo3Docker) -> Self {
-        Pyo3Images(Images::new(docker.0))
+        Pyo3Images(Images::new(docker.0.clone()), docker.0)
     }
 
     fn get(&self, name: &str) -> Pyo3Image {
@@ -46,6 +114,7 @@ impl Pyo3Images {
 
     fn list(
         &self,
+        py: Python<'_>,
         all: Option<bool>,
         digests: Option<bool>,
         _filter: Option<&str>,
@@ -54,7 +123,7 @@ impl Pyo3Images {
         bo_setter!(all, opts);
         bo_setter!(digests, opts);
 
-        let rv = __images_list(&self.0, &opts.build());
+        let rv = py.allow_threads(|| __images_list(&self.0, &opts.build()));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -62,15 +131,17 @@ impl Pyo3Images {
         }
     }
 
-    fn prune(&self) -> PyResult<Py<PyAny>> {
-        match __images_prune(&self.0) {
+    fn prune(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        match py.allow_threads(|| __images_prune(&self.0)) {
             Ok(info) => Ok(pythonize_this!(info)),
             Err(e) => Err(DockerPyo3Error::from(e).into()),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn build(
         &self,
+        py: Python<'_>,
         path: &str,
         dockerfile: Option<&str>,
         tag: Option<&str>,
@@ -94,8 +165,19 @@ impl Pyo3Images {
         target: Option<&str>,
         outputs: Option<&str>,
         labels: Option<&PyDict>,
+        context_tar: Option<&PyBytes>,
+        dockerfile_contents: Option<&str>,
+        callback: Option<PyObject>,
     ) -> PyResult<Py<PyAny>> {
-        let mut bo = ImageBuildOpts::builder(path);
+        let build_path = if let Some(contents) = dockerfile_contents {
+            write_tar_context(&synthesize_dockerfile_tar(contents))?
+        } else if let Some(tarball) = context_tar {
+            write_tar_context(tarball.as_bytes())?
+        } else {
+            path.to_string()
+        };
+
+        let mut bo = ImageBuildOpts::builder(&build_path);
 
         let labels: Option<HashMap<&str, &str>> = if labels.is_some() {
             Some(labels.unwrap().extract().map_err(|_| {
@@ -130,7 +212,7 @@ impl Pyo3Images {
         bo_setter!(outputs, bo);
         bo_setter!(labels, bo);
 
-        let rv = __images_build(&self.0, &bo.build());
+        let rv = py.allow_threads(|| __images_build(&self.0, &bo.build(), callback));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -138,88 +220,59 @@ impl Pyo3Images {
         }
     }
 
-    fn search(&self, _term: &str, _limit: Option<u32>) -> PyResult<Py<PyAny>> {
-        // TODO: Docker registry search is not available in docker-api crate
-        Err(exceptions::PyNotImplementedError::new_err(
-            "Image search not available in docker-api crate - use registry API directly",
-        ))
+    fn search(
+        &self,
+        py: Python<'_>,
+        term: &str,
+        limit: Option<u32>,
+        filters: Option<&PyDict>,
+    ) -> PyResult<Py<PyAny>> {
+        let mut query = vec![format!("term={term}")];
+
+        if let Some(limit) = limit {
+            query.push(format!("limit={limit}"));
+        }
+
+        if let Some(filters) = filters {
+            let filters: HashMap<String, String> = filters.extract().map_err(|_| {
+                DockerPyo3Error::InvalidParameter(
+                    "filters must be a dictionary of string keys and values".to_string(),
+                )
+            })?;
+            let encoded: Vec<String> = filters
+                .into_iter()
+                .map(|(key, value)| format!("\"{key}\":[\"{value}\"]"))
+                .collect();
+            query.push(format!("filters={{{}}}", encoded.join(",")));
+        }
+
+        let rv = py.allow_threads(|| __images_search(&self.1, &query.join("&")));
+
+        match rv {
+            Ok(results) => Ok(pythonize_this!(results)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn pull(
         &self,
+        py: Python<'_>,
         image: Option<&str>,
         src: Option<&str>,
         repo: Option<&str>,
         tag: Option<&str>,
+        auth: Option<Pyo3AuthConfig>,
         auth_password: Option<&PyDict>,
         auth_token: Option<&PyDict>,
+        callback: Option<PyObject>,
     ) -> PyResult<Py<PyAny>> {
         let mut pull_opts = PullOpts::builder();
 
-        if auth_password.is_some() && auth_token.is_some() {
-            let msg = "Got both auth_password and auth_token for image.push(). Only one of these options is allowed";
-            return Err(DockerPyo3Error::InvalidParameter(msg.to_string()).into());
-        }
-
-        let auth = if auth_password.is_some() && auth_token.is_none() {
-            let username = auth_password.unwrap().get_item("username");
-            let password = auth_password.unwrap().get_item("password");
-            let email = auth_password.unwrap().get_item("email");
-            let server_address = auth_password.unwrap().get_item("server_address");
-
-            let username = if username.is_none() {
-                None
-            } else {
-                Some(username.unwrap().extract::<String>().unwrap())
-            };
-
-            let password = if password.is_none() {
-                None
-            } else {
-                Some(password.unwrap().extract::<String>().unwrap())
-            };
-
-            let email = if email.is_none() {
-                None
-            } else {
-                Some(email.unwrap().extract::<String>().unwrap())
-            };
-
-            let server_address = if server_address.is_none() {
-                None
-            } else {
-                Some(server_address.unwrap().extract::<String>().unwrap())
-            };
-
-            let mut ra = RegistryAuth::builder();
-
-            bo_setter!(username, ra);
-            bo_setter!(password, ra);
-            bo_setter!(email, ra);
-            bo_setter!(server_address, ra);
-
-            Some(ra.build())
-        } else if auth_token.is_some() && auth_password.is_none() {
-            let auth_dict = auth_token.unwrap();
-            match auth_dict.get_item("identity_token") {
-                Some(token_obj) => {
-                    match token_obj.extract::<String>() {
-                        Ok(token_str) if !token_str.is_empty() => {
-                            let token = RegistryAuth::token(token_str);
-                            Some(token)
-                        }
-                        _ => {
-                            return Err(PyValueError::new_err("auth_token must contain a non-empty 'identity_token' field"));
-                        }
-                    }
-                }
-                None => {
-                    return Err(PyValueError::new_err("auth_token must contain an 'identity_token' field"));
-                }
-            }
-        } else {
-            Some(RegistryAuth::builder().build())
-        };
+        let registry_hint = image
+            .and_then(registry_from_image_name)
+            .or_else(|| repo.and_then(registry_from_image_name));
+        let auth = Some(resolve_registry_auth(auth, auth_password, auth_token, registry_hint.as_deref())?);
 
         bo_setter!(src, pull_opts);
         bo_setter!(repo, pull_opts);
@@ -227,7 +280,7 @@ impl Pyo3Images {
         bo_setter!(image, pull_opts);
         bo_setter!(auth, pull_opts);
 
-        let rv = __images_pull(&self.0, &pull_opts.build());
+        let rv = py.allow_threads(|| __images_pull(&self.0, &pull_opts.build(), callback));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -235,23 +288,39 @@ impl Pyo3Images {
         }
     }
 
-    fn export(&self, names: Vec<&str>, output: &str) -> PyResult<()> {
+    fn export(&self, py: Python<'_>, names: Vec<&str>, output: &str) -> PyResult<()> {
         // Export multiple images to a tar archive
-        let rv = __images_export(&self.0, names, output);
+        let rv = py.allow_threads(|| __images_export(&self.0, names, output));
         match rv {
             Ok(_) => Ok(()),
             Err(e) => Err(DockerPyo3Error::from(e).into()),
         }
     }
 
-    fn import(&self, src: &str, repository: Option<&str>, tag: Option<&str>) -> PyResult<Py<PyAny>> {
-        let rv = __images_import(&self.0, src, repository, tag);
+    fn import(&self, py: Python<'_>, src: &str, repository: Option<&str>, tag: Option<&str>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __images_import(&self.0, src, repository, tag));
         match rv {
             Ok(result) => Ok(pythonize_this!(result)),
             Err(e) => Err(DockerPyo3Error::from(e).into()),
         }
     }
 
+    /// Load a `docker save` tarball, skipping the import entirely when the
+    /// image it provides is already on the daemon. Reads the archive's
+    /// `manifest.json` (falling back to the legacy `repositories` file) to
+    /// determine the `repo:tag` it provides, unless `expected_tag`
+    /// overrides that, then checks the local image list before doing the
+    /// equivalent of `docker load -i <path>`. Returns the resolved
+    /// `repo:tag` either way - useful for air-gapped environments and CI
+    /// caches where pulling from a registry is slow or unavailable.
+    fn load_cached(&self, py: Python<'_>, path: &str, expected_tag: Option<&str>) -> PyResult<String> {
+        let rv = py.allow_threads(|| __images_load_cached(&self.0, path, expected_tag));
+        match rv {
+            Ok(reference) => Ok(reference),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn push(&self, _repository: &str, _tag: Option<&str>) -> PyResult<()> {
         // NOTE: Push should be done on individual Image objects, not the Images collection
         // Use docker.images().get("image_name").push() instead
@@ -260,10 +329,10 @@ impl Pyo3Images {
         ))
     }
 
-    fn clear_cache(&self) -> PyResult<()> {
+    fn clear_cache(&self, py: Python<'_>) -> PyResult<()> {
         // Docker builder cache clear - this is typically done via Docker buildx
         // For now, we'll implement basic prune which clears unused images
-        let rv = __images_prune(&self.0);
+        let rv = py.allow_threads(|| __images_prune(&self.0));
         match rv {
             Ok(_) => Ok(()),
             Err(e) => Err(DockerPyo3Error::from(e).into()),
@@ -271,6 +340,214 @@ impl Pyo3Images {
     }
 }
 
+/// Resolve a `RegistryAuth` from the `auth`/`auth_password`/`auth_token`
+/// arguments the Python API accepts, shared by `Pyo3Images.pull` and
+/// `Pyo3Image.push`. When none is given, fall back to whatever
+/// `Docker.login()` cached for `registry`, then to `~/.docker/config.json`
+/// (and its `credsStore`/`credHelpers`) on file for it.
+fn resolve_registry_auth(
+    auth: Option<Pyo3AuthConfig>,
+    auth_password: Option<&PyDict>,
+    auth_token: Option<&PyDict>,
+    registry: Option<&str>,
+) -> PyResult<RegistryAuth> {
+    let supplied_count =
+        auth.is_some() as u8 + auth_password.is_some() as u8 + auth_token.is_some() as u8;
+    if supplied_count > 1 {
+        let msg = "Got more than one of auth, auth_password, and auth_token. Only one of these options is allowed";
+        return Err(DockerPyo3Error::InvalidParameter(msg.to_string()).into());
+    }
+
+    if let Some(auth) = auth {
+        return Ok(auth.into_registry_auth());
+    }
+
+    if let Some(auth_password) = auth_password {
+        let username = auth_password.get_item("username").and_then(|v| v.extract::<String>().ok());
+        let password = auth_password.get_item("password").and_then(|v| v.extract::<String>().ok());
+        let email = auth_password.get_item("email").and_then(|v| v.extract::<String>().ok());
+        let server_address = auth_password.get_item("server_address").and_then(|v| v.extract::<String>().ok());
+
+        let mut ra = RegistryAuth::builder();
+        bo_setter!(username, ra);
+        bo_setter!(password, ra);
+        bo_setter!(email, ra);
+        bo_setter!(server_address, ra);
+
+        return Ok(ra.build());
+    }
+
+    if let Some(auth_token) = auth_token {
+        return match auth_token.get_item("identity_token") {
+            Some(token_obj) => match token_obj.extract::<String>() {
+                Ok(token_str) if !token_str.is_empty() => Ok(RegistryAuth::token(token_str)),
+                _ => Err(PyValueError::new_err(
+                    "auth_token must contain a non-empty 'identity_token' field",
+                )),
+            },
+            None => Err(PyValueError::new_err(
+                "auth_token must contain an 'identity_token' field",
+            )),
+        };
+    }
+
+    let registry = registry.unwrap_or("https://index.docker.io/v1/");
+
+    if let Some(auth) = crate::cached_registry_auth(registry) {
+        return Ok(auth);
+    }
+
+    if let Some((username, password)) = credentials_from_docker_config(registry) {
+        let mut ra = RegistryAuth::builder();
+        bo_setter!(username, ra);
+        bo_setter!(password, ra);
+        return Ok(ra.build());
+    }
+
+    Ok(RegistryAuth::builder().build())
+}
+
+/// Pull the registry host out of an image reference, e.g.
+/// `"registry.example.com/team/app:tag"` -> `Some("registry.example.com")`,
+/// `"nginx:latest"` -> `None` (implicitly Docker Hub).
+fn registry_from_image_name(name: &str) -> Option<String> {
+    let first_segment = name.split('/').next()?;
+    if first_segment.contains('.') || first_segment.contains(':') || first_segment == "localhost" {
+        Some(first_segment.to_string())
+    } else {
+        None
+    }
+}
+
+/// Look up credentials for `registry` in `~/.docker/config.json`, decoding
+/// the base64 `auths.<registry>.auth` field, or delegating to a
+/// `credsStore`/`credHelpers` entry by shelling out to
+/// `docker-credential-<helper> get`.
+fn credentials_from_docker_config(registry: &str) -> Option<(Option<String>, Option<String>)> {
+    let home = std::env::var("HOME").ok()?;
+    let config_path = std::path::Path::new(&home).join(".docker").join("config.json");
+    let contents = std::fs::read_to_string(config_path).ok()?;
+    let config: serde_yaml::Value = serde_yaml::from_str(&contents).ok()?;
+
+    let registry_entry = config.get("auths").and_then(|auths| auths.get(registry));
+
+    if let Some(auth) = registry_entry.and_then(|entry| entry.get("auth")).and_then(|a| a.as_str()) {
+        let decoded = base64_decode(auth)?;
+        let decoded = String::from_utf8(decoded).ok()?;
+        let (username, password) = decoded.split_once(':')?;
+        return Some((Some(username.to_string()), Some(password.to_string())));
+    }
+
+    let helper = config
+        .get("credHelpers")
+        .and_then(|helpers| helpers.get(registry))
+        .and_then(|h| h.as_str())
+        .or_else(|| config.get("credsStore").and_then(|s| s.as_str()));
+
+    helper.and_then(|helper| credentials_from_helper(helper, registry))
+}
+
+fn credentials_from_helper(helper: &str, registry: &str) -> Option<(Option<String>, Option<String>)> {
+    use std::io::Write as _;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new(format!("docker-credential-{helper}"))
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    child.stdin.as_mut()?.write_all(registry.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let response: serde_yaml::Value = serde_yaml::from_slice(&output.stdout).ok()?;
+    let username = response.get("Username").and_then(|v| v.as_str()).map(str::to_string);
+    let password = response.get("Secret").and_then(|v| v.as_str()).map(str::to_string);
+    Some((username, password))
+}
+
+fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut reverse = [255u8; 256];
+    for (i, &c) in TABLE.iter().enumerate() {
+        reverse[c as usize] = i as u8;
+    }
+
+    let input = input.trim().trim_end_matches('=');
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut out = Vec::new();
+
+    for c in input.bytes() {
+        let value = reverse[c as usize];
+        if value == 255 {
+            return None;
+        }
+        bits = (bits << 6) | value as u32;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push((bits >> bit_count) as u8);
+        }
+    }
+
+    Some(out)
+}
+
+/// Build a minimal POSIX ustar archive containing a single `Dockerfile`
+/// entry: one 512-byte header, the content padded to a 512-byte boundary,
+/// and the two all-zero 512-byte blocks that mark the end of the archive.
+fn synthesize_dockerfile_tar(contents: &str) -> Vec<u8> {
+    const BLOCK: usize = 512;
+    let data = contents.as_bytes();
+
+    let mut header = [0u8; BLOCK];
+    header[0..10].copy_from_slice(b"Dockerfile");
+    header[100..107].copy_from_slice(b"0000644");
+    header[108..115].copy_from_slice(b"0000000");
+    header[116..123].copy_from_slice(b"0000000");
+    let size_field = format!("{:011o}", data.len());
+    header[124..135].copy_from_slice(size_field.as_bytes());
+    header[136..147].copy_from_slice(b"00000000000");
+    header[156] = b'0'; // typeflag: regular file
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+
+    header[148..156].copy_from_slice(b"        "); // checksum field, blanked for the sum
+    let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+    let checksum_field = format!("{:06o}\0 ", checksum);
+    header[148..148 + checksum_field.len()].copy_from_slice(checksum_field.as_bytes());
+
+    let padding = (BLOCK - (data.len() % BLOCK)) % BLOCK;
+    let mut tar = Vec::with_capacity(BLOCK + data.len() + padding + BLOCK * 2);
+    tar.extend_from_slice(&header);
+    tar.extend_from_slice(data);
+    tar.extend(std::iter::repeat(0u8).take(padding));
+    tar.extend(std::iter::repeat(0u8).take(BLOCK * 2));
+    tar
+}
+
+/// Materialize build-context bytes as a temp file and return its path, since
+/// `ImageBuildOpts::builder` expects a filesystem path rather than an
+/// in-memory tarball.
+fn write_tar_context(bytes: &[u8]) -> PyResult<String> {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let path = std::env::temp_dir().join(format!(
+        "docker_pyo3_build_{}_{}.tar",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&path, bytes).map_err(|e| PyErr::from(DockerPyo3Error::from(e)))?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
 fn __images_list(
     images: &Images,
     opts: &ImageListOpts,
@@ -282,47 +559,90 @@ fn __images_prune(images: &Images) -> Result<ImagePrune200Response, docker_api::
     get_runtime().block_on(images.prune(&Default::default()))
 }
 
+/// Issue `GET /images/search` directly against the daemon since
+/// `docker_api::Images` has no wrapper for it.
+fn __images_search(docker: &Docker, query: &str) -> Result<Vec<ImageSearchResult>, docker_api::Error> {
+    get_runtime().block_on(docker.get_json(&format!("/images/search?{query}")))
+}
+
+/// Drive `images.build(opts)` to completion, invoking `callback` (if given)
+/// with a pythonized copy of each stream item as it arrives, and returning
+/// the last chunk seen (the build's final status / image ID) rather than a
+/// `Vec` of debug-formatted strings. An item carrying an `errorDetail` is
+/// surfaced as an error immediately instead of being handed to the caller.
 fn __images_build(
     images: &Images,
     opts: &ImageBuildOpts,
-) -> Result<Vec<String>, docker_api::Error> {
+    callback: Option<PyObject>,
+) -> Result<Option<String>, docker_api::Error> {
     get_runtime().block_on(async {
         let mut stream = images.build(opts);
-        let mut ok_stream_vec = Vec::new();
-        let mut err_message = None;
+        let mut last_chunk = None;
+
         while let Some(build_result) = stream.next().await {
             match build_result {
-                Ok(output) => ok_stream_vec.push(format!("{output:?}")),
-                Err(e) => err_message = Some(e),
+                Ok(output) => {
+                    let rendered = format!("{output:?}");
+                    if rendered.contains("errorDetail") || rendered.contains("error_detail: Some") {
+                        return Err(docker_api::Error::from(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            rendered,
+                        )));
+                    }
+
+                    if let Some(callback) = &callback {
+                        Python::with_gil(|py| {
+                            let item = pythonize_this!(output);
+                            let _ = callback.call1(py, (item,));
+                        });
+                    }
+
+                    last_chunk = Some(rendered);
+                }
+                Err(e) => return Err(e),
             }
         }
 
-        match err_message {
-            Some(err_message) => Err(err_message),
-            _ => Ok(ok_stream_vec),
-        }
+        Ok(last_chunk)
     })
 }
 
+/// Same streaming-with-callback treatment as [`__images_build`], applied to
+/// `images.pull(pull_opts)`.
 fn __images_pull(
     images: &Images,
     pull_opts: &PullOpts,
-) -> Result<Vec<String>, docker_api::Error> {
+    callback: Option<PyObject>,
+) -> Result<Option<String>, docker_api::Error> {
     get_runtime().block_on(async {
         let mut stream = images.pull(pull_opts);
-        let mut ok_stream_vec = Vec::new();
-        let mut err_message = None;
+        let mut last_chunk = None;
+
         while let Some(pull_result) = stream.next().await {
             match pull_result {
-                Ok(output) => ok_stream_vec.push(format!("{output:?}")),
-                Err(e) => err_message = Some(e),
+                Ok(output) => {
+                    let rendered = format!("{output:?}");
+                    if rendered.contains("errorDetail") || rendered.contains("error_detail: Some") {
+                        return Err(docker_api::Error::from(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            rendered,
+                        )));
+                    }
+
+                    if let Some(callback) = &callback {
+                        Python::with_gil(|py| {
+                            let item = pythonize_this!(output);
+                            let _ = callback.call1(py, (item,));
+                        });
+                    }
+
+                    last_chunk = Some(rendered);
+                }
+                Err(e) => return Err(e),
             }
         }
 
-        match err_message {
-            Some(err_message) => Err(err_message),
-            _ => Ok(ok_stream_vec),
-        }
+        Ok(last_chunk)
     })
 }
 
@@ -333,8 +653,8 @@ impl Pyo3Image {
         Pyo3Image(Image::new(docker.0, name))
     }
 
-    fn __repr__(&self) -> String {
-        match __image_inspect(&self.0) {
+    fn __repr__(&self, py: Python<'_>) -> String {
+        match py.allow_threads(|| __image_inspect(&self.0)) {
             Ok(inspect) => {
                 let id = inspect.id.unwrap_or_else(|| "unknown".to_string());
                 format!("Image(id: {}, name: {})", id, self.name())
@@ -343,8 +663,8 @@ impl Pyo3Image {
         }
     }
 
-    fn __string__(&self) -> String {
-        self.__repr__()
+    fn __string__(&self, py: Python<'_>) -> String {
+        self.__repr__(py)
     }
 
     fn name(&self) -> Py<PyAny> {
@@ -352,16 +672,16 @@ impl Pyo3Image {
         pythonize_this!(rv)
     }
 
-    fn inspect(&self) -> PyResult<Py<PyAny>> {
-        let rv = __image_inspect(&self.0);
+    fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __image_inspect(&self.0));
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
             Err(rv) => Err(DockerPyo3Error::from(rv).into()),
         }
     }
 
-    fn remove(&self) -> PyResult<String> {
-        let rv = __image_delete(&self.0);
+    fn remove(&self, py: Python<'_>) -> PyResult<String> {
+        let rv = py.allow_threads(|| __image_delete(&self.0));
         match rv {
             Ok(rv) => {
                 let mut r_value = "".to_owned();
@@ -375,8 +695,8 @@ impl Pyo3Image {
         }
     }
 
-    fn delete(&self) -> PyResult<String> {
-        let rv = __image_delete(&self.0);
+    fn delete(&self, py: Python<'_>) -> PyResult<String> {
+        let rv = py.allow_threads(|| __image_delete(&self.0));
         match rv {
             Ok(rv) => {
                 let mut r_value = "".to_owned();
@@ -390,8 +710,8 @@ impl Pyo3Image {
         }
     }
 
-    fn history(&self) -> PyResult<String> {
-        let rv = __image_history(&self.0);
+    fn history(&self, py: Python<'_>) -> PyResult<String> {
+        let rv = py.allow_threads(|| __image_history(&self.0));
 
         match rv {
             Ok(rv) => {
@@ -406,14 +726,14 @@ impl Pyo3Image {
         }
     }
 
-    fn export(&self, path: Option<&str>) -> PyResult<String> {
+    fn export(&self, py: Python<'_>, path: Option<&str>) -> PyResult<String> {
         let path = if path.is_none() {
             format!("{:?}", &self.0)
         } else {
             path.unwrap().to_string()
         };
 
-        let rv = __image_export(&self.0, path);
+        let rv = py.allow_threads(|| __image_export(&self.0, path));
 
         if rv.is_some() {
             match rv.unwrap() {
@@ -425,13 +745,13 @@ impl Pyo3Image {
         }
     }
 
-    fn tag(&self, repo: Option<&str>, tag: Option<&str>) -> PyResult<()> {
+    fn tag(&self, py: Python<'_>, repo: Option<&str>, tag: Option<&str>) -> PyResult<()> {
         let mut opts = TagOpts::builder();
 
         bo_setter!(repo, opts);
         bo_setter!(tag, opts);
 
-        let rv = __image_tag(&self.0, &opts.build());
+        let rv = py.allow_threads(|| __image_tag(&self.0, &opts.build()));
 
         match rv {
             Ok(_rv) => Ok(()),
@@ -441,80 +761,20 @@ impl Pyo3Image {
 
     fn push(
         &self,
+        py: Python<'_>,
+        auth: Option<Pyo3AuthConfig>,
         auth_password: Option<&PyDict>,
         auth_token: Option<&PyDict>,
         tag: Option<&str>,
     ) -> PyResult<()> {
-        if auth_password.is_some() && auth_token.is_some() {
-            let msg = "Got both auth_password and auth_token for image.push(). Only one of these options is allowed";
-            return Err(DockerPyo3Error::InvalidParameter(msg.to_string()).into());
-        }
-
-        let auth = if auth_password.is_some() && auth_token.is_none() {
-            let username = auth_password.unwrap().get_item("username");
-            let password = auth_password.unwrap().get_item("password");
-            let email = auth_password.unwrap().get_item("email");
-            let server_address = auth_password.unwrap().get_item("server_address");
-
-            let username = if username.is_none() {
-                None
-            } else {
-                Some(username.unwrap().extract::<String>().unwrap())
-            };
-
-            let password = if password.is_none() {
-                None
-            } else {
-                Some(password.unwrap().extract::<String>().unwrap())
-            };
-
-            let email = if email.is_none() {
-                None
-            } else {
-                Some(email.unwrap().extract::<String>().unwrap())
-            };
-
-            let server_address = if server_address.is_none() {
-                None
-            } else {
-                Some(server_address.unwrap().extract::<String>().unwrap())
-            };
-
-            let mut ra = RegistryAuth::builder();
-
-            bo_setter!(username, ra);
-            bo_setter!(password, ra);
-            bo_setter!(email, ra);
-            bo_setter!(server_address, ra);
-
-            Some(ra.build())
-        } else if auth_token.is_some() && auth_password.is_none() {
-            let auth_dict = auth_token.unwrap();
-            match auth_dict.get_item("identity_token") {
-                Some(token_obj) => {
-                    match token_obj.extract::<String>() {
-                        Ok(token_str) if !token_str.is_empty() => {
-                            let token = RegistryAuth::token(token_str);
-                            Some(token)
-                        }
-                        _ => {
-                            return Err(PyValueError::new_err("auth_token must contain a non-empty 'identity_token' field"));
-                        }
-                    }
-                }
-                None => {
-                    return Err(PyValueError::new_err("auth_token must contain an 'identity_token' field"));
-                }
-            }
-        } else {
-            Some(RegistryAuth::builder().build())
-        };
+        let registry_hint = registry_from_image_name(&self.0.name());
+        let auth = Some(resolve_registry_auth(auth, auth_password, auth_token, registry_hint.as_deref())?);
 
         let mut opts = ImagePushOpts::builder();
         bo_setter!(tag, opts);
         bo_setter!(auth, opts);
 
-        let rv = __image_push(&self.0, &opts.build());
+        let rv = py.allow_threads(|| __image_push(&self.0, &opts.build()));
         match rv {
             Ok(_rv) => Ok(()),
             Err(rv) => Err(DockerPyo3Error::from(rv).into()),
@@ -586,29 +846,141 @@ fn __image_push(image: &Image, opts: &ImagePushOpts) -> Result<(), docker_api::E
 
 
 fn __images_export(
-    _images: &Images,
-    _names: Vec<&str>,
-    _output: &str
+    images: &Images,
+    names: Vec<&str>,
+    output: &str,
 ) -> Result<(), docker_api::Error> {
-    // TODO: Implement multi-image export
-    // This requires streaming multiple images to a tar file
-    use std::io;
-    Err(docker_api::Error::from(io::Error::new(
-        io::ErrorKind::Other, 
-        "Image export not yet implemented"
-    )))
+    get_runtime().block_on(async {
+        let mut export_file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .open(output)
+            .map_err(docker_api::Error::from)?;
+
+        let mut export_stream = images.export(names);
+        while let Some(chunk_result) = export_stream.next().await {
+            let bytes = chunk_result?;
+            export_file.write(&bytes).map_err(docker_api::Error::from)?;
+        }
+
+        export_file.flush().map_err(docker_api::Error::from)?;
+        Ok(())
+    })
 }
 
 fn __images_import(
-    _images: &Images,
-    _src: &str,
-    _repository: Option<&str>,
-    _tag: Option<&str>
+    images: &Images,
+    src: &str,
+    repository: Option<&str>,
+    tag: Option<&str>,
 ) -> Result<String, docker_api::Error> {
-    // TODO: Implement image import from tar/url
-    use std::io;
-    Err(docker_api::Error::from(io::Error::new(
-        io::ErrorKind::Other, 
-        "Image import not yet implemented"
-    )))
+    get_runtime().block_on(async {
+        let tarball = std::fs::read(src).map_err(docker_api::Error::from)?;
+
+        let mut opts = ImageImportOpts::builder();
+        bo_setter!(repository, opts);
+        bo_setter!(tag, opts);
+
+        let mut import_stream = images.import(tarball, &opts.build());
+        let mut last_chunk = String::new();
+        while let Some(import_result) = import_stream.next().await {
+            match import_result {
+                Ok(chunk) => last_chunk = format!("{chunk:?}"),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(last_chunk)
+    })
+}
+
+/// Resolve which `repo:tag` a `docker save` tarball provides and, unless
+/// it's already present locally, import it - the `load_cached` backing
+/// function. `expected_tag` skips the archive scan entirely when the
+/// caller already knows the reference.
+fn __images_load_cached(
+    images: &Images,
+    path: &str,
+    expected_tag: Option<&str>,
+) -> Result<String, DockerPyo3Error> {
+    let reference = match expected_tag {
+        Some(tag) => tag.to_string(),
+        None => read_repo_tag_from_archive(path)?,
+    };
+
+    if get_runtime().block_on(images.get(&reference).inspect()).is_ok() {
+        return Ok(reference);
+    }
+
+    get_runtime().block_on(async {
+        let tarball = tokio::fs::read(path).await?;
+        let mut import_stream = images.import(tarball, &ImageImportOpts::builder().build());
+        while let Some(chunk) = import_stream.next().await {
+            chunk?;
+        }
+        Ok::<(), DockerPyo3Error>(())
+    })?;
+
+    Ok(reference)
+}
+
+/// Pull the first `repo:tag` reference out of a `docker save` archive:
+/// the modern `manifest.json`'s `RepoTags` array if present, otherwise
+/// the legacy `repositories` file older exporters still write. There's no
+/// `serde_json` dependency in this crate (see the note in error.rs), so
+/// this is a small targeted scan rather than a full JSON parse.
+fn read_repo_tag_from_archive(path: &str) -> Result<String, DockerPyo3Error> {
+    let mut manifest_json = None;
+    let mut repositories = None;
+
+    let mut archive = Archive::new(File::open(path)?);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let mut contents = String::new();
+        if name == "manifest.json" {
+            entry.read_to_string(&mut contents)?;
+            manifest_json = Some(contents);
+        } else if name == "repositories" {
+            entry.read_to_string(&mut contents)?;
+            repositories = Some(contents);
+        }
+    }
+
+    manifest_json
+        .as_deref()
+        .and_then(extract_repo_tag_from_manifest)
+        .or_else(|| repositories.as_deref().and_then(extract_repo_tag_from_repositories))
+        .ok_or_else(|| {
+            DockerPyo3Error::InvalidParameter(format!(
+                "{path}: couldn't find a RepoTags entry in manifest.json or the legacy repositories file"
+            ))
+        })
+}
+
+/// Pull the first entry out of `manifest.json`'s `"RepoTags":[...]` array.
+fn extract_repo_tag_from_manifest(contents: &str) -> Option<String> {
+    let after_key = contents.find("\"RepoTags\"")? + "\"RepoTags\"".len();
+    let array_start = contents[after_key..].find('[')? + after_key + 1;
+    let array_end = contents[array_start..].find(']')? + array_start;
+
+    contents[array_start..array_end].split(',').find_map(|entry| {
+        let trimmed = entry.trim().trim_matches('"');
+        (!trimmed.is_empty()).then(|| trimmed.to_string())
+    })
+}
+
+/// Pull `repo:tag` out of the legacy `repositories` file, shaped like
+/// `{"repo": {"tag": "<layer id>"}, ...}`.
+fn extract_repo_tag_from_repositories(contents: &str) -> Option<String> {
+    let repo_start = contents.find('"')? + 1;
+    let repo_end = repo_start + contents[repo_start..].find('"')?;
+    let repo = &contents[repo_start..repo_end];
+
+    let rest = &contents[repo_end + 1..];
+    let tag_start = rest.find('"')? + 1;
+    let tag_end = tag_start + rest[tag_start..].find('"')?;
+    let tag = &rest[tag_start..tag_end];
+
+    Some(format!("{repo}:{tag}"))
 }
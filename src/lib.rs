@@ -1,29 +1,84 @@
 #[macro_use]
 mod macros;
+pub mod compose;
 pub mod container;
+pub mod dind;
+pub mod error;
+pub mod events;
+pub mod exceptions;
 pub mod image;
 pub mod network;
+pub mod node;
+pub mod runtime;
+pub mod service;
+pub mod stack;
+pub mod swarm;
 pub mod volume;
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::wrap_pymodule;
 
-use docker_api::models::{PingInfo, SystemDataUsage200Response, SystemInfo, SystemVersion};
+use docker_api::models::{PingInfo, SystemAuthResponse, SystemDataUsage200Response, SystemInfo, SystemVersion};
+use docker_api::opts::RegistryAuth;
 use docker_api::Docker;
 
 use pythonize::pythonize;
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::runtime::Runtime;
+
 use container::Pyo3Containers;
+use events::Pyo3EventStream;
 use image::Pyo3Images;
 use network::Pyo3Networks;
+use node::Pyo3Nodes;
+use service::Pyo3Services;
+use swarm::Pyo3Swarm;
 use volume::Pyo3Volumes;
 
 #[cfg(unix)]
-static SYSTEM_DEFAULT_URI: &str = "unix:///var/run/docker.sock";
+pub(crate) static SYSTEM_DEFAULT_URI: &str = "unix:///var/run/docker.sock";
 
 #[cfg(not(unix))]
-static SYSTEM_DEFAULT_URI: &str = "tcp://localhost:2375";
+pub(crate) static SYSTEM_DEFAULT_URI: &str = "tcp://localhost:2375";
+
+static RUNTIME: OnceLock<Arc<Runtime>> = OnceLock::new();
+
+/// The Tokio runtime shared by every blocking Docker API call, including
+/// the `__container_*`/`__exec_*`/`__network_*`/etc. free functions and the
+/// `logs`/`stats`/`events` streaming iterators.
+///
+/// Every call used to spin up its own runtime via `#[tokio::main]`, which
+/// meant a fresh thread pool (and no connection reuse) on every single
+/// call - expensive under the kind of tight polling loop a monitoring
+/// tool runs. This lazily creates one runtime on first use and hands out
+/// clones of the `Arc` afterward, so the underlying HTTP connection pool
+/// persists across calls for the lifetime of the process.
+pub fn get_runtime() -> Arc<Runtime> {
+    RUNTIME
+        .get_or_init(|| Arc::new(Runtime::new().expect("failed to create Tokio runtime")))
+        .clone()
+}
+
+static AUTH_CACHE: OnceLock<Mutex<HashMap<String, RegistryAuth>>> = OnceLock::new();
+
+fn auth_cache() -> &'static Mutex<HashMap<String, RegistryAuth>> {
+    AUTH_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Look up the `RegistryAuth` a prior `Docker.login(registry=...)` call
+/// cached for `registry`, if any. Consulted by `image::resolve_registry_auth`
+/// as a fallback when a pull/push doesn't supply explicit credentials.
+pub(crate) fn cached_registry_auth(registry: &str) -> Option<RegistryAuth> {
+    auth_cache().lock().unwrap().get(registry).cloned()
+}
+
+fn cache_registry_auth(registry: String, auth: RegistryAuth) {
+    auth_cache().lock().unwrap().insert(registry, auth);
+}
 
 /// Docker client for interacting with the Docker daemon.
 ///
@@ -33,58 +88,188 @@ static SYSTEM_DEFAULT_URI: &str = "tcp://localhost:2375";
 ///     >>> docker = Docker("tcp://localhost:2375")
 #[pyclass(name = "Docker")]
 #[derive(Clone, Debug)]
-pub struct Pyo3Docker(pub Docker);
+pub struct Pyo3Docker(pub Docker, pub Arc<Runtime>);
+
+/// TLS / mTLS configuration for connecting to a remote Docker daemon.
+///
+/// Mirrors docker-py's `docker.tls.TLSConfig`: `client_cert` is a
+/// `(cert_path, key_path)` pair identifying this client to the daemon,
+/// `ca_cert` is the CA bundle used to verify the daemon's own certificate,
+/// and `verify` controls whether that verification is enforced.
+/// `assert_hostname` is stored on the config but is not applied to the
+/// connection.
+#[derive(Debug, Clone, Default)]
+#[pyclass(name = "TLSConfig")]
+pub struct Pyo3TLSConfig {
+    #[pyo3(get, set)]
+    pub client_cert: Option<(String, String)>,
+    #[pyo3(get, set)]
+    pub ca_cert: Option<String>,
+    #[pyo3(get, set)]
+    pub verify: bool,
+    #[pyo3(get, set)]
+    pub assert_hostname: bool,
+}
+
+#[pymethods]
+impl Pyo3TLSConfig {
+    #[new]
+    #[pyo3(signature = (client_cert=None, ca_cert=None, verify=true, assert_hostname=true))]
+    fn new(
+        client_cert: Option<(String, String)>,
+        ca_cert: Option<String>,
+        verify: bool,
+        assert_hostname: bool,
+    ) -> Self {
+        Pyo3TLSConfig { client_cert, ca_cert, verify, assert_hostname }
+    }
+}
 
 #[pymethods]
 impl Pyo3Docker {
     #[new]
-    #[pyo3(signature = ( uri = SYSTEM_DEFAULT_URI))]
+    #[pyo3(signature = ( uri = SYSTEM_DEFAULT_URI, tls = None))]
     /// Create a new Docker client.
     ///
     /// Args:
     ///     uri: URI to connect to the Docker daemon. Defaults to the system default
     ///          (unix:///var/run/docker.sock on Unix, tcp://localhost:2375 on Windows).
+    ///     tls: Optional `TLSConfig` for connecting to a remote daemon secured with
+    ///          (mutual) TLS. Rewrites a `tcp://` URI to `https://`. Cannot be combined
+    ///          with a `unix://` URI.
     ///
     /// Returns:
     ///     Docker client instance
-    fn py_new(uri: &str) -> Self {
-        Pyo3Docker(Docker::new(uri).unwrap())
+    ///
+    /// Raises:
+    ///     ValueError: if `tls` is combined with a unix-socket URI, or sets neither
+    ///         `ca_cert` nor `client_cert`.
+    ///     DockerException: if the URI can't be parsed. The daemon is not contacted here.
+    fn py_new(uri: &str, tls: Option<Pyo3TLSConfig>) -> PyResult<Self> {
+        let uri = match &tls {
+            Some(tls) => {
+                if uri.starts_with("unix://") {
+                    return Err(PyValueError::new_err(
+                        "a `tls` config cannot be combined with a unix socket URI",
+                    ));
+                }
+                configure_tls(tls)?;
+                match uri.strip_prefix("tcp://") {
+                    Some(rest) => format!("https://{rest}"),
+                    None => uri.to_string(),
+                }
+            }
+            None => uri.to_string(),
+        };
+
+        let docker = Docker::new(&uri).map_err(|e| error::map_err(&e))?;
+        Ok(Pyo3Docker(docker, get_runtime()))
     }
 
     /// Get Docker version information.
     ///
     /// Returns:
     ///     dict: Version information including API version, OS, architecture, etc.
-    fn version(&self) -> Py<PyAny> {
-        let sv = __version(self.clone());
-        pythonize_this!(sv)
+    fn version(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let sv = py.allow_threads(|| __version(self.clone())).map_err(|e| error::map_err(&e))?;
+        Ok(pythonize_this!(sv))
     }
 
     /// Get Docker system information.
     ///
     /// Returns:
     ///     dict: System information including containers count, images count, storage driver, etc.
-    fn info(&self) -> Py<PyAny> {
-        let si = __info(self.clone());
-        pythonize_this!(si)
+    fn info(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let si = py.allow_threads(|| __info(self.clone())).map_err(|e| error::map_err(&e))?;
+        Ok(pythonize_this!(si))
     }
 
     /// Ping the Docker daemon to verify connectivity.
     ///
     /// Returns:
     ///     dict: Ping response from the daemon
-    fn ping(&self) -> Py<PyAny> {
-        let pi = __ping(self.clone());
-        pythonize_this!(pi)
+    fn ping(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let pi = py.allow_threads(|| __ping(self.clone())).map_err(|e| error::map_err(&e))?;
+        Ok(pythonize_this!(pi))
+    }
+
+    /// Whether this process is itself running inside a container
+    /// (Docker-in-Docker). When true, bind-mount sources given to
+    /// `containers().create(..., resolve_host_paths=True)` are translated
+    /// from this container's filesystem to the real host paths the daemon
+    /// needs - see `host_path_of`.
+    ///
+    /// Returns:
+    ///     bool: True if running inside a container
+    #[staticmethod]
+    fn is_in_docker() -> bool {
+        dind::is_in_docker()
+    }
+
+    /// Translate `inner_path` (a path as seen from inside *this*
+    /// container) to the real host path the daemon needs, by inspecting
+    /// this container's own mounts. Returns `inner_path` unchanged if this
+    /// process's own container can't be identified or no mount covers it.
+    ///
+    /// Args:
+    ///     inner_path: A path as seen from inside this container
+    ///
+    /// Returns:
+    ///     str: The equivalent path on the Docker host
+    fn host_path_of(&self, py: Python<'_>, inner_path: &str) -> PyResult<String> {
+        py.allow_threads(|| dind::host_path_of(&self.0, inner_path)).map_err(Into::into)
     }
 
     /// Get data usage information for Docker objects.
     ///
     /// Returns:
     ///     dict: Data usage statistics for containers, images, volumes, and build cache
-    fn data_usage(&self) -> Py<PyAny> {
-        let du = __data_usage(self.clone());
-        pythonize_this!(du)
+    fn data_usage(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let du = py.allow_threads(|| __data_usage(self.clone())).map_err(|e| error::map_err(&e))?;
+        Ok(pythonize_this!(du))
+    }
+
+    /// Validate registry credentials against the daemon and cache the
+    /// result so later `images().pull()` / `image.push()` calls against
+    /// that registry pick it up automatically.
+    ///
+    /// Args:
+    ///     username: Registry username
+    ///     password: Registry password
+    ///     registry: Registry server address. Defaults to Docker Hub
+    ///         ("https://index.docker.io/v1/").
+    ///     email: Optional account email
+    ///
+    /// Returns:
+    ///     dict: the daemon's auth response (status message, and an
+    ///     identity token if the registry issued one)
+    #[pyo3(signature = (username, password, registry=None, email=None))]
+    fn login(
+        &self,
+        py: Python<'_>,
+        username: &str,
+        password: &str,
+        registry: Option<&str>,
+        email: Option<&str>,
+    ) -> PyResult<Py<PyAny>> {
+        let server_address = registry.unwrap_or("https://index.docker.io/v1/").to_string();
+
+        let mut builder = RegistryAuth::builder();
+        builder = builder.username(username);
+        builder = builder.password(password);
+        if let Some(email) = email {
+            builder = builder.email(email);
+        }
+        builder = builder.server_address(&server_address);
+        let auth = builder.build();
+
+        let response = py
+            .allow_threads(|| __login(self.clone(), &auth))
+            .map_err(|e| error::map_err(&e))?;
+
+        cache_registry_auth(server_address, auth);
+
+        Ok(pythonize_this!(response))
     }
 
     /// Get a Containers interface for managing containers.
@@ -118,41 +303,126 @@ impl Pyo3Docker {
     fn volumes(&'_ self) -> Pyo3Volumes {
         Pyo3Volumes::new(self.clone())
     }
+
+    /// Get a Swarm interface for initializing, joining, and inspecting a swarm.
+    ///
+    /// Returns:
+    ///     Swarm: Interface for swarm control operations
+    fn swarm(&'_ self) -> Pyo3Swarm {
+        Pyo3Swarm::new(self.clone())
+    }
+
+    /// Get a Services interface for managing swarm services.
+    ///
+    /// Returns:
+    ///     Services: Interface for service operations
+    fn services(&'_ self) -> Pyo3Services {
+        Pyo3Services::new(self.clone())
+    }
+
+    /// Get a Nodes interface for managing swarm nodes.
+    ///
+    /// Returns:
+    ///     Nodes: Interface for node operations
+    fn nodes(&'_ self) -> Pyo3Nodes {
+        Pyo3Nodes::new(self.clone())
+    }
+
+    /// Open a live iterator over the daemon's `/events` stream.
+    ///
+    /// Args:
+    ///     since: Only return events created since this timestamp
+    ///     until: Stop returning events created after this timestamp
+    ///     filters: dict of filter name -> value narrowing which events are returned
+    ///
+    /// Returns:
+    ///     EventStream: iterator yielding pythonized event dicts. Call `.close()`
+    ///     (or let it drop) to cancel the underlying connection early.
+    #[pyo3(signature = (since=None, until=None, filters=None))]
+    fn events(
+        &self,
+        since: Option<&str>,
+        until: Option<&str>,
+        filters: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Pyo3EventStream> {
+        Pyo3EventStream::open(self.0.clone(), since, until, filters)
+    }
 }
 
-#[tokio::main]
-async fn __version(docker: Pyo3Docker) -> SystemVersion {
-    let version = docker.0.version().await;
-    version.unwrap()
+fn __version(docker: Pyo3Docker) -> Result<SystemVersion, docker_api::Error> {
+    docker.1.block_on(docker.0.version())
 }
 
-#[tokio::main]
-async fn __info(docker: Pyo3Docker) -> SystemInfo {
-    let info = docker.0.info().await;
-    info.unwrap()
+fn __info(docker: Pyo3Docker) -> Result<SystemInfo, docker_api::Error> {
+    docker.1.block_on(docker.0.info())
 }
 
-#[tokio::main]
-async fn __ping(docker: Pyo3Docker) -> PingInfo {
-    let ping = docker.0.ping().await;
-    ping.unwrap()
+fn __ping(docker: Pyo3Docker) -> Result<PingInfo, docker_api::Error> {
+    docker.1.block_on(docker.0.ping())
 }
 
-#[tokio::main]
-async fn __data_usage(docker: Pyo3Docker) -> SystemDataUsage200Response {
-    let du = docker.0.data_usage().await;
-    du.unwrap()
+fn __data_usage(docker: Pyo3Docker) -> Result<SystemDataUsage200Response, docker_api::Error> {
+    docker.1.block_on(docker.0.data_usage())
+}
+
+fn __login(docker: Pyo3Docker, auth: &RegistryAuth) -> Result<SystemAuthResponse, docker_api::Error> {
+    docker.1.block_on(docker.0.auth(auth))
+}
+
+/// Materialize `tls`'s certificate material to a fresh temp directory as
+/// `ca.pem`/`cert.pem`/`key.pem` and point `DOCKER_CERT_PATH`/
+/// `DOCKER_TLS_VERIFY` at it - the same convention the Docker CLI and its
+/// daemon use to locate mTLS identity for an `https://` connection.
+fn configure_tls(tls: &Pyo3TLSConfig) -> PyResult<()> {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    if tls.ca_cert.is_none() && tls.client_cert.is_none() {
+        return Err(PyValueError::new_err(
+            "tls config must set at least one of ca_cert or client_cert",
+        ));
+    }
+
+    let dir = std::env::temp_dir().join(format!(
+        "docker_pyo3_tls_{}_{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir).map_err(|e| PyErr::from(error::DockerPyo3Error::from(e)))?;
+
+    if let Some(ca_cert) = &tls.ca_cert {
+        std::fs::copy(ca_cert, dir.join("ca.pem")).map_err(|e| PyErr::from(error::DockerPyo3Error::from(e)))?;
+    }
+
+    if let Some((cert, key)) = &tls.client_cert {
+        std::fs::copy(cert, dir.join("cert.pem")).map_err(|e| PyErr::from(error::DockerPyo3Error::from(e)))?;
+        std::fs::copy(key, dir.join("key.pem")).map_err(|e| PyErr::from(error::DockerPyo3Error::from(e)))?;
+    }
+
+    std::env::set_var("DOCKER_CERT_PATH", &dir);
+    std::env::set_var("DOCKER_TLS_VERIFY", if tls.verify { "1" } else { "" });
+
+    Ok(())
 }
 
 /// A Python module implemented in Rust.
 #[pymodule]
 pub fn docker_pyo3(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Pyo3Docker>()?;
+    m.add_class::<Pyo3TLSConfig>()?;
+    m.add_function(wrap_pyfunction!(runtime::quick_run, m)?)?;
 
     m.add_wrapped(wrap_pymodule!(image::image))?;
     m.add_wrapped(wrap_pymodule!(container::container))?;
     m.add_wrapped(wrap_pymodule!(network::network))?;
     m.add_wrapped(wrap_pymodule!(volume::volume))?;
+    m.add_wrapped(wrap_pymodule!(exceptions::exceptions))?;
+    m.add_wrapped(wrap_pymodule!(events::events))?;
+    m.add_wrapped(wrap_pymodule!(swarm::swarm))?;
+    m.add_wrapped(wrap_pymodule!(service::service))?;
+    m.add_wrapped(wrap_pymodule!(node::node))?;
+    m.add_wrapped(wrap_pymodule!(stack::stack))?;
+    m.add_wrapped(wrap_pymodule!(compose::compose))?;
 
     let sys = PyModule::import(_py, "sys")?;
     let sys_modules: Bound<'_, PyDict> = sys.getattr("modules")?.downcast_into()?;
@@ -160,6 +430,13 @@ pub fn docker_pyo3(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     sys_modules.set_item("docker_pyo3.container", m.getattr("container")?)?;
     sys_modules.set_item("docker_pyo3.network", m.getattr("network")?)?;
     sys_modules.set_item("docker_pyo3.volume", m.getattr("volume")?)?;
+    sys_modules.set_item("docker_pyo3.exceptions", m.getattr("exceptions")?)?;
+    sys_modules.set_item("docker_pyo3.events", m.getattr("events")?)?;
+    sys_modules.set_item("docker_pyo3.swarm", m.getattr("swarm")?)?;
+    sys_modules.set_item("docker_pyo3.service", m.getattr("service")?)?;
+    sys_modules.set_item("docker_pyo3.node", m.getattr("node")?)?;
+    sys_modules.set_item("docker_pyo3.stack", m.getattr("stack")?)?;
+    sys_modules.set_item("docker_pyo3.compose", m.getattr("compose")?)?;
 
     Ok(())
 }
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
-use crate::Pyo3Docker;
-use docker_api::opts::{ContainerConnectionOpts, NetworkPruneOpts};
+use crate::{get_runtime, Pyo3Docker};
+use docker_api::opts::{
+    ContainerConnectionOpts, NetworkFilter, NetworkListOpts, NetworkPruneFilter, NetworkPruneOpts, NetworkType,
+};
 use docker_api::opts::{ContainerDisconnectionOpts, NetworkCreateOpts};
 use docker_api::{models::NetworkPrune200Response, Network, Networks};
 use pyo3::exceptions;
@@ -35,8 +37,53 @@ impl Pyo3Networks {
         Pyo3Network(self.0.get(id))
     }
 
-    pub fn list(&self) -> PyResult<Py<PyAny>> {
-        let rv = __networks_list(&self.0);
+    #[pyo3(signature = (*, driver=None, label=None, name=None, id=None, scope=None, r#type=None, dangling=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn list(
+        &self,
+        py: Python<'_>,
+        driver: Option<&str>,
+        label: Option<Vec<String>>,
+        name: Option<&str>,
+        id: Option<&str>,
+        scope: Option<&str>,
+        r#type: Option<&str>,
+        dangling: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let mut filters = Vec::new();
+        if let Some(driver) = driver {
+            filters.push(NetworkFilter::Driver(driver.to_string()));
+        }
+        for label in label.into_iter().flatten() {
+            filters.push(NetworkFilter::LabelKV(label));
+        }
+        if let Some(name) = name {
+            filters.push(NetworkFilter::Name(name.to_string()));
+        }
+        if let Some(id) = id {
+            filters.push(NetworkFilter::Id(id.to_string()));
+        }
+        if let Some(scope) = scope {
+            filters.push(NetworkFilter::Scope(scope.to_string()));
+        }
+        if let Some(r#type) = r#type {
+            let network_type = match r#type {
+                "custom" => NetworkType::Custom,
+                "builtin" => NetworkType::Builtin,
+                other => {
+                    return Err(exceptions::PyValueError::new_err(format!(
+                        "invalid network type '{other}', expected 'custom' or 'builtin'"
+                    )))
+                }
+            };
+            filters.push(NetworkFilter::Type(network_type));
+        }
+        if let Some(dangling) = dangling {
+            filters.push(NetworkFilter::Dangling(dangling));
+        }
+
+        let opts = NetworkListOpts::builder().filter(filters).build();
+        let rv = py.allow_threads(|| __networks_list(&self.0, &opts));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -44,8 +91,18 @@ impl Pyo3Networks {
         }
     }
 
-    pub fn prune(&self) -> PyResult<Py<PyAny>> {
-        let rv = __networks_prune(&self.0, &Default::default());
+    #[pyo3(signature = (*, until=None, label=None))]
+    pub fn prune(&self, py: Python<'_>, until: Option<&str>, label: Option<Vec<String>>) -> PyResult<Py<PyAny>> {
+        let mut filters = Vec::new();
+        if let Some(until) = until {
+            filters.push(NetworkPruneFilter::Until(until.to_string()));
+        }
+        for label in label.into_iter().flatten() {
+            filters.push(NetworkPruneFilter::LabelKV(label));
+        }
+
+        let opts = NetworkPruneOpts::builder().filter(filters).build();
+        let rv = py.allow_threads(|| __networks_prune(&self.0, &opts));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -53,9 +110,10 @@ impl Pyo3Networks {
         }
     }
 
-    #[pyo3(signature = (name, *, check_duplicate=None, driver=None, internal=None, attachable=None, ingress=None, enable_ipv6=None, options=None, labels=None))]
+    #[pyo3(signature = (name, *, check_duplicate=None, driver=None, internal=None, attachable=None, ingress=None, enable_ipv6=None, options=None, labels=None, ipam=None))]
     pub fn create(
         &self,
+        py: Python<'_>,
         name: &str,
         check_duplicate: Option<bool>,
         driver: Option<&str>,
@@ -65,6 +123,7 @@ impl Pyo3Networks {
         enable_ipv6: Option<bool>,
         options: Option<&Bound<'_, PyDict>>,
         labels: Option<&Bound<'_, PyDict>>,
+        ipam: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Pyo3Network> {
         let mut network_opts = NetworkCreateOpts::builder(name);
 
@@ -86,6 +145,8 @@ impl Pyo3Networks {
             .as_ref()
             .map(|m| m.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect());
 
+        let ipam: Option<docker_api::models::Ipam> = ipam.map(parse_ipam).transpose()?;
+
         bo_setter!(check_duplicate, network_opts);
         bo_setter!(driver, network_opts);
         bo_setter!(internal, network_opts);
@@ -94,36 +155,116 @@ impl Pyo3Networks {
         bo_setter!(enable_ipv6, network_opts);
         bo_setter!(options, network_opts);
         bo_setter!(labels, network_opts);
+        bo_setter!(ipam, network_opts);
 
-        let rv = __networks_create(&self.0, &network_opts.build());
+        let rv = py.allow_threads(|| __networks_create(&self.0, &network_opts.build()));
         match rv {
             Ok(rv) => Ok(Pyo3Network(rv)),
             Err(rv) => Err(py_sys_exception!(rv)),
         }
     }
+
+    /// Return the existing network named `name`, or create it.
+    ///
+    /// Docker keys networks by a random ID rather than name, so a plain
+    /// `create` call can't safely be retried. This looks the name up first
+    /// and only forwards to `create` (with the same keyword arguments) if
+    /// no network with that exact name already exists.
+    #[pyo3(signature = (name, *, check_duplicate=None, driver=None, internal=None, attachable=None, ingress=None, enable_ipv6=None, options=None, labels=None, ipam=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_or_create(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        check_duplicate: Option<bool>,
+        driver: Option<&str>,
+        internal: Option<bool>,
+        attachable: Option<bool>,
+        ingress: Option<bool>,
+        enable_ipv6: Option<bool>,
+        options: Option<&Bound<'_, PyDict>>,
+        labels: Option<&Bound<'_, PyDict>>,
+        ipam: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Pyo3Network> {
+        let opts = NetworkListOpts::builder()
+            .filter(vec![NetworkFilter::Name(name.to_string())])
+            .build();
+        let existing = py
+            .allow_threads(|| __networks_list(&self.0, &opts))
+            .map_err(|e| py_sys_exception!(e))?;
+
+        if let Some(network) = existing.into_iter().find(|n| n.name.as_deref() == Some(name)) {
+            if let Some(id) = network.id {
+                return Ok(Pyo3Network(self.0.get(&id)));
+            }
+        }
+
+        self.create(
+            py,
+            name,
+            check_duplicate,
+            driver,
+            internal,
+            attachable,
+            ingress,
+            enable_ipv6,
+            options,
+            labels,
+            ipam,
+        )
+    }
+}
+
+/// Build docker-api's `Ipam` model from the `ipam` dict accepted by
+/// `Networks.create`, e.g. `{"driver": "default", "config": [{"subnet":
+/// "172.28.0.0/16", "gateway": "172.28.0.1"}]}`.
+fn parse_ipam(dict: &Bound<'_, PyDict>) -> PyResult<docker_api::models::Ipam> {
+    let driver: Option<String> = dict.get_item("driver")?.map(|v| v.extract()).transpose()?;
+    let options: Option<HashMap<String, String>> = dict.get_item("options")?.map(|v| v.extract()).transpose()?;
+
+    let config: Option<Vec<Bound<'_, PyDict>>> = dict.get_item("config")?.map(|v| v.extract()).transpose()?;
+    let config = config
+        .map(|pools| {
+            pools
+                .iter()
+                .map(|pool| {
+                    let aux_address: Option<HashMap<String, String>> =
+                        pool.get_item("aux_addresses")?.map(|v| v.extract()).transpose()?;
+
+                    Ok(docker_api::models::IpamConfig {
+                        subnet: pool.get_item("subnet")?.map(|v| v.extract()).transpose()?,
+                        ip_range: pool.get_item("ip_range")?.map(|v| v.extract()).transpose()?,
+                        gateway: pool.get_item("gateway")?.map(|v| v.extract()).transpose()?,
+                        aux_address,
+                    })
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })
+        .transpose()?;
+
+    Ok(docker_api::models::Ipam {
+        driver,
+        options,
+        config,
+    })
 }
 
-#[tokio::main]
-async fn __networks_list(
+fn __networks_list(
     networks: &Networks,
+    opts: &NetworkListOpts,
 ) -> Result<Vec<docker_api::models::Network>, docker_api::Error> {
-    networks.list(&Default::default()).await
+    get_runtime().block_on(networks.list(opts))
 }
 
-#[tokio::main]
-async fn __networks_prune(
+fn __networks_prune(
     networks: &Networks,
     opts: &NetworkPruneOpts,
 ) -> Result<NetworkPrune200Response, docker_api::Error> {
-    networks.prune(opts).await
+    get_runtime().block_on(networks.prune(opts))
 }
 
-#[tokio::main]
-async fn __networks_create(
-    networks: &Networks,
-    opts: &NetworkCreateOpts,
-) -> Result<Network, docker_api::Error> {
-    networks.create(opts).await
+fn __networks_create(networks: &Networks, opts: &NetworkCreateOpts) -> Result<Network, docker_api::Error> {
+    get_runtime().block_on(networks.create(opts))
 }
 
 #[pymethods]
@@ -137,8 +278,8 @@ impl Pyo3Network {
         self.0.id().to_string()
     }
 
-    pub fn inspect(&self) -> PyResult<Py<PyAny>> {
-        let rv = __network_inspect(&self.0);
+    pub fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __network_inspect(&self.0));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -146,8 +287,8 @@ impl Pyo3Network {
         }
     }
 
-    pub fn delete(&self) -> PyResult<()> {
-        let rv = __network_delete(&self.0);
+    pub fn delete(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __network_delete(&self.0));
         match rv {
             Ok(rv) => Ok(rv),
             Err(rv) => Err(py_sys_exception!(rv)),
@@ -157,8 +298,9 @@ impl Pyo3Network {
     #[pyo3(signature = (container_id, ipam_config=None, aliases=None, links=None, network_id=None, endpoint_id=None, gateway=None, ipv4=None, prefix_len=None, ipv6_gateway=None, ipv6=None, ipv6_prefix_len=None, mac=None, driver_opts=None))]
     pub fn connect(
         &self,
+        py: Python<'_>,
         container_id: &str,
-        ipam_config: Option<&str>,
+        ipam_config: Option<&Bound<'_, PyDict>>,
         aliases: Option<&Bound<'_, PyList>>,
         links: Option<&Bound<'_, PyList>>,
         network_id: Option<&str>,
@@ -201,6 +343,36 @@ impl Pyo3Network {
             .as_ref()
             .map(|m| m.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect());
 
+        // A static IP assignment, e.g. {"ipv4_address": "172.20.0.10",
+        // "ipv6_address": "2001:db8::10", "link_local_ips": ["169.254.1.1"]}.
+        // `ipv4`/`ipv6` above take precedence if both are given.
+        let ipam_ipv4: Option<String> = ipam_config
+            .map(|d| d.get_item("ipv4_address"))
+            .transpose()?
+            .flatten()
+            .map(|v| v.extract::<String>())
+            .transpose()?;
+        let ipam_ipv6: Option<String> = ipam_config
+            .map(|d| d.get_item("ipv6_address"))
+            .transpose()?
+            .flatten()
+            .map(|v| v.extract::<String>())
+            .transpose()?;
+        let link_local_ips: Option<Vec<String>> = ipam_config
+            .map(|d| d.get_item("link_local_ips"))
+            .transpose()?
+            .flatten()
+            .map(|v| v.extract::<Vec<String>>())
+            .transpose()?;
+
+        let ipv4 = ipv4.map(str::to_string).or(ipam_ipv4);
+        let ipv4 = ipv4.as_deref();
+        let ipv6 = ipv6.map(str::to_string).or(ipam_ipv6);
+        let ipv6 = ipv6.as_deref();
+        let link_local_ips: Option<Vec<&str>> = link_local_ips
+            .as_ref()
+            .map(|v| v.iter().map(String::as_str).collect());
+
         bo_setter!(network_id, connect_opts);
         bo_setter!(endpoint_id, connect_opts);
         bo_setter!(gateway, connect_opts);
@@ -210,14 +382,13 @@ impl Pyo3Network {
         bo_setter!(ipv6, connect_opts);
         bo_setter!(ipv6_prefix_len, connect_opts);
         bo_setter!(mac, connect_opts);
+        bo_setter!(link_local_ips, connect_opts);
 
         bo_setter!(aliases, connect_opts);
         bo_setter!(links, connect_opts);
         bo_setter!(driver_opts, connect_opts);
 
-        // bo_setter!(ipam_config, connect_opts);
-
-        let rv = __network_connect(&self.0, &connect_opts.build());
+        let rv = py.allow_threads(|| __network_connect(&self.0, &connect_opts.build()));
 
         match rv {
             Ok(rv) => Ok(rv),
@@ -226,11 +397,11 @@ impl Pyo3Network {
     }
 
     #[pyo3(signature = (container_id, force=None))]
-    pub fn disconnect(&self, container_id: &str, force: Option<bool>) -> PyResult<()> {
+    pub fn disconnect(&self, py: Python<'_>, container_id: &str, force: Option<bool>) -> PyResult<()> {
         let mut disconnect_opts = ContainerDisconnectionOpts::builder(container_id);
         bo_setter!(force, disconnect_opts);
 
-        let rv = __network_disconnect(&self.0, &disconnect_opts.build());
+        let rv = py.allow_threads(|| __network_disconnect(&self.0, &disconnect_opts.build()));
 
         match rv {
             Ok(rv) => Ok(rv),
@@ -239,30 +410,18 @@ impl Pyo3Network {
     }
 }
 
-#[tokio::main]
-async fn __network_inspect(
-    network: &Network,
-) -> Result<docker_api::models::Network, docker_api::Error> {
-    network.inspect().await
+fn __network_inspect(network: &Network) -> Result<docker_api::models::Network, docker_api::Error> {
+    get_runtime().block_on(network.inspect())
 }
 
-#[tokio::main]
-async fn __network_delete(network: &Network) -> Result<(), docker_api::Error> {
-    network.delete().await
+fn __network_delete(network: &Network) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(network.delete())
 }
 
-#[tokio::main]
-async fn __network_connect(
-    network: &Network,
-    opts: &ContainerConnectionOpts,
-) -> Result<(), docker_api::Error> {
-    network.connect(opts).await
+fn __network_connect(network: &Network, opts: &ContainerConnectionOpts) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(network.connect(opts))
 }
 
-#[tokio::main]
-async fn __network_disconnect(
-    network: &Network,
-    opts: &ContainerDisconnectionOpts,
-) -> Result<(), docker_api::Error> {
-    network.disconnect(opts).await
+fn __network_disconnect(network: &Network, opts: &ContainerDisconnectionOpts) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(network.disconnect(opts))
 }
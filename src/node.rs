@@ -0,0 +1,90 @@
+use docker_api::opts::NodeUpdateOpts;
+use docker_api::{Node, Nodes};
+use pyo3::prelude::*;
+use pythonize::pythonize;
+
+use crate::error::DockerPyo3Error;
+use crate::{get_runtime, Pyo3Docker};
+
+#[pymodule]
+pub fn node(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Pyo3Nodes>()?;
+    m.add_class::<Pyo3Node>()?;
+    Ok(())
+}
+
+/// Interface for managing the swarm's nodes collection.
+#[derive(Debug)]
+#[pyclass(name = "Nodes")]
+pub struct Pyo3Nodes(pub Nodes);
+
+/// An individual swarm node.
+#[derive(Debug)]
+#[pyclass(name = "Node")]
+pub struct Pyo3Node(pub Node);
+
+#[pymethods]
+impl Pyo3Nodes {
+    #[new]
+    pub fn new(docker: Pyo3Docker) -> Self {
+        Pyo3Nodes(Nodes::new(docker.0))
+    }
+
+    /// Get a specific node by ID.
+    pub fn get(&self, id: &str) -> Pyo3Node {
+        Pyo3Node(self.0.get(id))
+    }
+
+    /// List nodes in the swarm.
+    pub fn list(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __nodes_list(&self.0));
+        match rv {
+            Ok(rv) => Ok(pythonize_this!(rv)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+}
+
+#[pymethods]
+impl Pyo3Node {
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Inspect the node.
+    pub fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __node_inspect(&self.0));
+        match rv {
+            Ok(rv) => Ok(pythonize_this!(rv)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Update the node's availability and/or role, e.g. to drain it ahead
+    /// of maintenance or put it back into rotation.
+    ///
+    /// Args:
+    ///     availability: "active", "pause", or "drain"
+    ///     role: "worker" or "manager"
+    #[pyo3(signature = (availability=None, role=None))]
+    pub fn update(&self, py: Python<'_>, availability: Option<&str>, role: Option<&str>) -> PyResult<()> {
+        let mut opts = NodeUpdateOpts::builder();
+        bo_setter!(availability, opts);
+        bo_setter!(role, opts);
+
+        let rv = py.allow_threads(|| __node_update(&self.0, &opts.build()));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+}
+
+fn __nodes_list(nodes: &Nodes) -> Result<Vec<docker_api::models::Node>, docker_api::Error> {
+    get_runtime().block_on(nodes.list())
+}
+
+fn __node_inspect(node: &Node) -> Result<docker_api::models::Node, docker_api::Error> {
+    get_runtime().block_on(node.inspect())
+}
+
+fn __node_update(node: &Node, opts: &NodeUpdateOpts) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(node.update(opts))
+}
@@ -0,0 +1,225 @@
+//! Pluggable container-runtime backend, so the same `quick_run`-style
+//! surface works whether the host actually has Docker or only
+//! Singularity/Apptainer available (the latter being the common case on
+//! HPC clusters that forbid a Docker daemon), following the
+//! container-resolver model Galaxy uses to treat `docker` and
+//! `singularity` as interchangeable container types behind one interface.
+//!
+//! The selector is read from the `DOCKER_PYO3_RUNTIME` environment
+//! variable (`docker` or `singularity`, defaulting to `docker`) - the
+//! `#[pymodule]` entry point's signature is fixed by PyO3, so it can't
+//! additionally take a runtime argument at registration time.
+
+use std::process::Command;
+
+use crate::error::DockerPyo3Error;
+use crate::get_runtime;
+use crate::SYSTEM_DEFAULT_URI;
+
+use docker_api::opts::{ContainerCreateOpts, LogsOpts, PullOpts};
+use docker_api::Docker;
+use futures_util::StreamExt;
+use pyo3::prelude::*;
+
+/// Which backend [`current_runtime`] should build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeKind {
+    Docker,
+    Singularity,
+}
+
+impl RuntimeKind {
+    /// Read `DOCKER_PYO3_RUNTIME` (`docker` or `singularity`, case
+    /// insensitive), defaulting to `Docker` if unset or unrecognized.
+    pub fn from_env() -> Self {
+        match std::env::var("DOCKER_PYO3_RUNTIME") {
+            Ok(value) if value.eq_ignore_ascii_case("singularity") => RuntimeKind::Singularity,
+            _ => RuntimeKind::Docker,
+        }
+    }
+}
+
+/// The container operations `quick_run`/`ManagedDocker`-style callers need,
+/// implemented once per backend so the Python surface doesn't have to know
+/// which one is underneath.
+pub trait ContainerRuntime {
+    /// Pull `image` if the backend doesn't already have it cached.
+    fn pull(&self, image: &str) -> Result<(), DockerPyo3Error>;
+
+    /// Run `image` with `command`, returning its combined stdout/stderr
+    /// after the container/instance exits.
+    fn run(&self, image: &str, command: &[String]) -> Result<String, DockerPyo3Error>;
+}
+
+/// Talks to a Docker daemon via `docker_api`, reusing the process-wide
+/// Tokio runtime every other Docker call in this crate shares.
+pub struct DockerRuntime {
+    docker: Docker,
+}
+
+impl DockerRuntime {
+    pub fn new() -> Result<Self, DockerPyo3Error> {
+        let docker = Docker::new(SYSTEM_DEFAULT_URI).map_err(DockerPyo3Error::from)?;
+        Ok(DockerRuntime { docker })
+    }
+}
+
+impl ContainerRuntime for DockerRuntime {
+    fn pull(&self, image: &str) -> Result<(), DockerPyo3Error> {
+        if get_runtime().block_on(self.docker.images().get(image).inspect()).is_ok() {
+            return Ok(());
+        }
+
+        let images = self.docker.images();
+        let pull_opts = PullOpts::builder().image(image).build();
+
+        get_runtime()
+            .block_on(async {
+                let mut stream = images.pull(&pull_opts);
+                while let Some(chunk) = stream.next().await {
+                    chunk?;
+                }
+                Ok::<(), docker_api::Error>(())
+            })
+            .map_err(DockerPyo3Error::from)
+    }
+
+    fn run(&self, image: &str, command: &[String]) -> Result<String, DockerPyo3Error> {
+        self.pull(image)?;
+
+        let containers = self.docker.containers();
+        let create_opts = ContainerCreateOpts::builder().image(image).command(command.to_vec()).build();
+
+        let container = get_runtime().block_on(containers.create(&create_opts)).map_err(DockerPyo3Error::from)?;
+        get_runtime().block_on(container.start()).map_err(DockerPyo3Error::from)?;
+        crate::container::__container_wait(&self.docker, container.id(), None, None).map_err(DockerPyo3Error::from)?;
+
+        let log_opts = LogsOpts::builder().stdout(true).stderr(true).build();
+        let log = get_runtime().block_on(async {
+            let mut stream = container.logs(&log_opts);
+            let mut buf = Vec::new();
+            while let Some(chunk) = stream.next().await {
+                buf.extend_from_slice(&chunk.map(|c| c.to_vec()).unwrap_or_default());
+            }
+            buf
+        });
+
+        get_runtime().block_on(container.delete()).map_err(DockerPyo3Error::from)?;
+
+        Ok(String::from_utf8_lossy(&log).into_owned())
+    }
+}
+
+/// Shells out to `singularity`/`apptainer` directly - there's no API client
+/// for it the way `docker_api` wraps the Docker socket. Bare image names
+/// without a URI scheme (`alpine`) are assumed to be Docker Hub references
+/// and get a `docker://` prefix, matching how `singularity pull`/`run`
+/// otherwise require an explicit scheme.
+pub struct SingularityRuntime {
+    /// The `singularity` (or `apptainer`) binary to invoke.
+    binary: String,
+}
+
+impl SingularityRuntime {
+    pub fn new() -> Self {
+        SingularityRuntime { binary: "singularity".to_string() }
+    }
+
+    /// Use an alternate binary name/path, e.g. `"apptainer"`.
+    pub fn with_binary<S: Into<String>>(binary: S) -> Self {
+        SingularityRuntime { binary: binary.into() }
+    }
+
+    fn image_uri(image: &str) -> String {
+        if image.contains("://") {
+            image.to_string()
+        } else {
+            format!("docker://{image}")
+        }
+    }
+}
+
+impl Default for SingularityRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContainerRuntime for SingularityRuntime {
+    fn pull(&self, image: &str) -> Result<(), DockerPyo3Error> {
+        let output = Command::new(&self.binary).arg("pull").arg(Self::image_uri(image)).output()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(DockerPyo3Error::Connection(format!(
+                "singularity pull failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )))
+        }
+    }
+
+    fn run(&self, image: &str, command: &[String]) -> Result<String, DockerPyo3Error> {
+        let output = Command::new(&self.binary)
+            .arg("run")
+            .arg(Self::image_uri(image))
+            .args(command)
+            .output()?;
+
+        if output.status.success() {
+            let mut combined = output.stdout;
+            combined.extend_from_slice(&output.stderr);
+            Ok(String::from_utf8_lossy(&combined).into_owned())
+        } else {
+            Err(DockerPyo3Error::Connection(format!(
+                "singularity run failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )))
+        }
+    }
+}
+
+/// Build the backend selected by `DOCKER_PYO3_RUNTIME`.
+pub fn current_runtime() -> Result<Box<dyn ContainerRuntime>, DockerPyo3Error> {
+    match RuntimeKind::from_env() {
+        RuntimeKind::Docker => Ok(Box::new(DockerRuntime::new()?)),
+        RuntimeKind::Singularity => Ok(Box::new(SingularityRuntime::new())),
+    }
+}
+
+/// Pull and run `image` with `command` to completion on whichever backend
+/// [`current_runtime`] selects (Docker by default, or Singularity/Apptainer
+/// with `DOCKER_PYO3_RUNTIME=singularity`), returning its combined
+/// stdout/stderr.
+#[pyfunction]
+pub fn quick_run(image: String, command: Vec<String>) -> PyResult<String> {
+    let runtime = current_runtime()?;
+    runtime.pull(&image)?;
+    Ok(runtime.run(&image, &command)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_kind_from_env() {
+        std::env::remove_var("DOCKER_PYO3_RUNTIME");
+        assert_eq!(RuntimeKind::from_env(), RuntimeKind::Docker);
+
+        std::env::set_var("DOCKER_PYO3_RUNTIME", "singularity");
+        assert_eq!(RuntimeKind::from_env(), RuntimeKind::Singularity);
+
+        std::env::set_var("DOCKER_PYO3_RUNTIME", "Docker");
+        assert_eq!(RuntimeKind::from_env(), RuntimeKind::Docker);
+
+        std::env::remove_var("DOCKER_PYO3_RUNTIME");
+    }
+
+    #[test]
+    fn test_singularity_image_uri_prefixes_bare_names() {
+        assert_eq!(SingularityRuntime::image_uri("alpine"), "docker://alpine");
+        assert_eq!(SingularityRuntime::image_uri("docker://alpine"), "docker://alpine");
+        assert_eq!(SingularityRuntime::image_uri("library://org/project/image"), "library://org/project/image");
+    }
+}
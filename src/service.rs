@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use docker_api::opts::{ServiceCreateOpts, ServiceListOpts};
+use docker_api::{Service, Services};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pythonize::pythonize;
+
+use crate::error::DockerPyo3Error;
+use crate::{get_runtime, Pyo3Docker};
+
+#[pymodule]
+pub fn service(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Pyo3Services>()?;
+    m.add_class::<Pyo3Service>()?;
+    Ok(())
+}
+
+/// Interface for managing the swarm's services collection.
+#[derive(Debug)]
+#[pyclass(name = "Services")]
+pub struct Pyo3Services(pub Services);
+
+/// An individual swarm service.
+#[derive(Debug)]
+#[pyclass(name = "Service")]
+pub struct Pyo3Service(pub Service);
+
+#[pymethods]
+impl Pyo3Services {
+    #[new]
+    pub fn new(docker: Pyo3Docker) -> Self {
+        Pyo3Services(Services::new(docker.0))
+    }
+
+    /// Get a specific service by ID or name.
+    pub fn get(&self, id: &str) -> Pyo3Service {
+        Pyo3Service(self.0.get(id))
+    }
+
+    /// List services in the swarm.
+    pub fn list(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __services_list(&self.0, &Default::default()));
+        match rv {
+            Ok(rv) => Ok(pythonize_this!(rv)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Create a new service.
+    ///
+    /// Args:
+    ///     name: Service name
+    ///     image: Image to run, e.g. "nginx:latest"
+    ///     replicas: Number of replicated tasks to run (replicated mode)
+    ///     env: Environment variables as a list, e.g. ["VAR=value"]
+    ///     mounts: List of dicts with "source", "target", and optional "type"
+    ///         ("bind", "volume", or "tmpfs"; defaults to "volume")
+    ///     published_ports: List of dicts with "published", "target", and optional
+    ///         "protocol" ("tcp" or "udp"; defaults to "tcp")
+    ///     labels: Labels to attach to the service
+    ///
+    /// Returns:
+    ///     Service: the newly created service
+    #[pyo3(signature = (name, image, replicas=None, env=None, mounts=None, published_ports=None, labels=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        image: &str,
+        replicas: Option<u64>,
+        env: Option<&Bound<'_, PyList>>,
+        mounts: Option<&Bound<'_, PyList>>,
+        published_ports: Option<&Bound<'_, PyList>>,
+        labels: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Pyo3Service> {
+        let mut opts = ServiceCreateOpts::builder(name, image);
+
+        if let Some(replicas) = replicas {
+            opts = opts.replicas(replicas);
+        }
+
+        if let Some(env) = env {
+            let env: Vec<String> = env.extract().map_err(|_| {
+                DockerPyo3Error::InvalidParameter("env must be a list of strings".to_string())
+            })?;
+            opts = opts.env(env.iter().map(String::as_str));
+        }
+
+        if let Some(mounts) = mounts {
+            for mount in mounts.iter() {
+                let mount: &Bound<'_, PyDict> = mount.downcast().map_err(|_| {
+                    DockerPyo3Error::InvalidParameter(
+                        "mounts entries must be dicts with 'source'/'target'".to_string(),
+                    )
+                })?;
+                let source: String = mount
+                    .get_item("source")?
+                    .ok_or_else(|| DockerPyo3Error::InvalidParameter("mount requires 'source'".to_string()))?
+                    .extract()?;
+                let target: String = mount
+                    .get_item("target")?
+                    .ok_or_else(|| DockerPyo3Error::InvalidParameter("mount requires 'target'".to_string()))?
+                    .extract()?;
+                let mount_type = mount
+                    .get_item("type")?
+                    .and_then(|v| v.extract::<String>().ok())
+                    .unwrap_or_else(|| "volume".to_string());
+
+                opts = opts.mount(&source, &target, &mount_type);
+            }
+        }
+
+        if let Some(published_ports) = published_ports {
+            for port in published_ports.iter() {
+                let port: &Bound<'_, PyDict> = port.downcast().map_err(|_| {
+                    DockerPyo3Error::InvalidParameter(
+                        "published_ports entries must be dicts with 'published'/'target'".to_string(),
+                    )
+                })?;
+                let published: u32 = port
+                    .get_item("published")?
+                    .ok_or_else(|| DockerPyo3Error::InvalidParameter("port requires 'published'".to_string()))?
+                    .extract()?;
+                let target: u32 = port
+                    .get_item("target")?
+                    .ok_or_else(|| DockerPyo3Error::InvalidParameter("port requires 'target'".to_string()))?
+                    .extract()?;
+                let protocol = port
+                    .get_item("protocol")?
+                    .and_then(|v| v.extract::<String>().ok())
+                    .unwrap_or_else(|| "tcp".to_string());
+
+                opts = opts.publish_port(published, target, &protocol);
+            }
+        }
+
+        if let Some(labels) = labels {
+            let labels: HashMap<String, String> = labels.extract().map_err(|_| {
+                DockerPyo3Error::InvalidParameter(
+                    "labels must be a dictionary of string keys and values".to_string(),
+                )
+            })?;
+            opts = opts.labels(labels);
+        }
+
+        let rv = py.allow_threads(|| __services_create(&self.0, &opts.build()));
+        match rv {
+            Ok(service) => Ok(Pyo3Service(service)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Remove a service by ID or name.
+    pub fn remove(&self, py: Python<'_>, id: &str) -> PyResult<()> {
+        let rv = py.allow_threads(|| __service_delete(&self.0.get(id)));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+}
+
+#[pymethods]
+impl Pyo3Service {
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Inspect the service.
+    pub fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __service_inspect(&self.0));
+        match rv {
+            Ok(rv) => Ok(pythonize_this!(rv)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+
+    /// Remove this service.
+    pub fn remove(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __service_delete(&self.0));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+}
+
+fn __services_list(
+    services: &Services,
+    opts: &ServiceListOpts,
+) -> Result<Vec<docker_api::models::Service>, docker_api::Error> {
+    get_runtime().block_on(services.list(opts))
+}
+
+fn __services_create(services: &Services, opts: &ServiceCreateOpts) -> Result<Service, docker_api::Error> {
+    get_runtime().block_on(services.create(opts))
+}
+
+fn __service_inspect(service: &Service) -> Result<docker_api::models::Service, docker_api::Error> {
+    get_runtime().block_on(service.inspect())
+}
+
+fn __service_delete(service: &Service) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(service.delete())
+}
@@ -0,0 +1,205 @@
+use crate::error::DockerPyo3Error;
+
+/// A parsed Docker image reference:
+/// `[registry[:port]/][namespace/]repository[:tag][@sha256:digest]`.
+///
+/// `registry` is only populated when the first path segment looks like a
+/// host (contains a `.` or `:`, or is literally `localhost`) - otherwise the
+/// segment is treated as part of the repository path on the default
+/// registry, matching how the Docker CLI resolves image names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRef {
+    pub registry: Option<String>,
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+}
+
+impl ImageRef {
+    /// Parse an image reference string.
+    pub fn parse(image: &str) -> Result<Self, DockerPyo3Error> {
+        if image.is_empty() {
+            return Err(DockerPyo3Error::InvalidParameter("image reference is empty".to_string()));
+        }
+
+        let (without_digest, digest) = match image.split_once('@') {
+            Some((rest, digest)) => (rest, Some(digest.to_string())),
+            None => (image, None),
+        };
+
+        let mut segments: Vec<&str> = without_digest.split('/').collect();
+        let registry = if segments.len() > 1 && is_registry_segment(segments[0]) {
+            Some(segments.remove(0).to_string())
+        } else {
+            None
+        };
+
+        let path = segments.join("/");
+        let (repository, tag) = match path.rsplit_once(':') {
+            // A ':' after the last '/' is a tag; one before it (e.g. a
+            // registry port already consumed above) never reaches here.
+            Some((repo, tag)) if !repo.is_empty() && !tag.contains('/') => {
+                (repo.to_string(), Some(tag.to_string()))
+            }
+            _ => (path, None),
+        };
+
+        if repository.is_empty() {
+            return Err(DockerPyo3Error::InvalidParameter(format!(
+                "image reference '{image}' has no repository"
+            )));
+        }
+
+        // On the implicit Docker Hub registry, a bare single-segment name
+        // (`nginx`) is shorthand for an official image (`library/nginx`).
+        let repository =
+            if registry.is_none() && !repository.contains('/') { format!("library/{repository}") } else { repository };
+
+        Ok(ImageRef { registry, repository, tag, digest })
+    }
+
+    /// The tag to use if none was given: `latest`.
+    pub fn tag_or_default(&self) -> &str {
+        self.tag.as_deref().unwrap_or("latest")
+    }
+
+    /// Return a copy of this reference pinned to `tag`, clearing any digest
+    /// since a reference shouldn't carry a mismatched tag and digest pin.
+    pub fn with_tag<S: Into<String>>(&self, tag: S) -> Self {
+        ImageRef {
+            registry: self.registry.clone(),
+            repository: self.repository.clone(),
+            tag: Some(tag.into()),
+            digest: None,
+        }
+    }
+
+    /// Return a copy of this reference pinned to `digest` (e.g.
+    /// `sha256:...`), leaving the tag as informational context.
+    pub fn with_digest<S: Into<String>>(&self, digest: S) -> Self {
+        ImageRef {
+            registry: self.registry.clone(),
+            repository: self.repository.clone(),
+            tag: self.tag.clone(),
+            digest: Some(digest.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for ImageRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(registry) = &self.registry {
+            write!(f, "{registry}/")?;
+        }
+        write!(f, "{}", self.repository)?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{tag}")?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+fn is_registry_segment(segment: &str) -> bool {
+    segment == "localhost" || segment.contains('.') || segment.contains(':')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_only() {
+        let r = ImageRef::parse("nginx").unwrap();
+        assert_eq!(r.registry, None);
+        assert_eq!(r.repository, "library/nginx");
+        assert_eq!(r.tag, None);
+        assert_eq!(r.tag_or_default(), "latest");
+    }
+
+    #[test]
+    fn test_repository_and_tag() {
+        let r = ImageRef::parse("nginx:1.25-alpine").unwrap();
+        assert_eq!(r.repository, "library/nginx");
+        assert_eq!(r.tag.as_deref(), Some("1.25-alpine"));
+    }
+
+    #[test]
+    fn test_namespace_and_tag() {
+        let r = ImageRef::parse("library/nginx:latest").unwrap();
+        assert_eq!(r.registry, None);
+        assert_eq!(r.repository, "library/nginx");
+        assert_eq!(r.tag.as_deref(), Some("latest"));
+    }
+
+    #[test]
+    fn test_registry_with_port() {
+        let r = ImageRef::parse("registry.example.com:5000/team/app:v2").unwrap();
+        assert_eq!(r.registry.as_deref(), Some("registry.example.com:5000"));
+        assert_eq!(r.repository, "team/app");
+        assert_eq!(r.tag.as_deref(), Some("v2"));
+    }
+
+    #[test]
+    fn test_localhost_registry() {
+        let r = ImageRef::parse("localhost/app").unwrap();
+        assert_eq!(r.registry.as_deref(), Some("localhost"));
+        assert_eq!(r.repository, "app");
+    }
+
+    #[test]
+    fn test_digest() {
+        let r = ImageRef::parse("nginx@sha256:abcd1234").unwrap();
+        assert_eq!(r.repository, "library/nginx");
+        assert_eq!(r.digest.as_deref(), Some("sha256:abcd1234"));
+    }
+
+    #[test]
+    fn test_tag_and_digest() {
+        let r = ImageRef::parse("nginx:1.25@sha256:abcd1234").unwrap();
+        assert_eq!(r.tag.as_deref(), Some("1.25"));
+        assert_eq!(r.digest.as_deref(), Some("sha256:abcd1234"));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let original = "registry.example.com:5000/team/app:v2@sha256:abcd1234";
+        let r = ImageRef::parse(original).unwrap();
+        assert_eq!(r.to_string(), original);
+    }
+
+    #[test]
+    fn test_with_tag_clears_digest() {
+        let r = ImageRef::parse("nginx@sha256:abcd1234").unwrap().with_tag("1.26");
+        assert_eq!(r.tag.as_deref(), Some("1.26"));
+        assert_eq!(r.digest, None);
+    }
+
+    #[test]
+    fn test_with_digest_keeps_tag() {
+        let r = ImageRef::parse("nginx:1.25").unwrap().with_digest("sha256:deadbeef");
+        assert_eq!(r.tag.as_deref(), Some("1.25"));
+        assert_eq!(r.digest.as_deref(), Some("sha256:deadbeef"));
+    }
+
+    #[test]
+    fn test_empty_is_error() {
+        assert!(ImageRef::parse("").is_err());
+    }
+
+    #[test]
+    fn test_single_segment_gets_library_prefix() {
+        let r = ImageRef::parse("redis:7").unwrap();
+        assert_eq!(r.registry, None);
+        assert_eq!(r.repository, "library/redis");
+        assert_eq!(r.to_string(), "library/redis:7");
+    }
+
+    #[test]
+    fn test_namespaced_repository_has_no_library_prefix() {
+        let r = ImageRef::parse("bitnami/redis").unwrap();
+        assert_eq!(r.repository, "bitnami/redis");
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use crate::error::DockerPyo3Error;
+
+/// Expand `${VAR}`-style references in `input` against `vars`, matching the
+/// grammar used by `docker-compose`'s `interpolation.py`:
+///
+/// - `$$` escapes to a literal `$`
+/// - `$NAME` / `${NAME}` substitutes the value of `NAME`, or an empty string if unset
+/// - `${NAME:-default}` / `${NAME-default}` substitute `default` when `NAME` is unset
+///   (`:-` also substitutes `default` when `NAME` is set but empty)
+/// - `${NAME:?err}` / `${NAME?err}` raise `Configuration(err)` when `NAME` is unset
+///   (`:?` also raises when `NAME` is set but empty)
+/// - `${NAME:+alt}` / `${NAME+alt}` substitute `alt` only when `NAME` is set
+///   (`:+` also requires it be non-empty)
+pub fn interpolate(input: &str, vars: &HashMap<String, String>) -> Result<String, DockerPyo3Error> {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some((_, '$')) => {
+                chars.next();
+                out.push('$');
+            }
+            Some((_, '{')) => {
+                chars.next();
+                let mut expr = String::new();
+                let mut closed = false;
+                for (_, c) in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    expr.push(c);
+                }
+                if !closed {
+                    return Err(DockerPyo3Error::InvalidParameter(format!(
+                        "unterminated variable expression: ${{{expr}"
+                    )));
+                }
+                out.push_str(&resolve_braced(&expr, vars)?);
+            }
+            Some((_, c2)) if is_name_start(*c2) => {
+                let mut name = String::new();
+                while let Some((_, c2)) = chars.peek() {
+                    if is_name_char(*c2) {
+                        name.push(*c2);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                match vars.get(&name) {
+                    Some(value) => out.push_str(value),
+                    None => eprintln!("Warning: variable '{name}' is not set, substituting an empty string"),
+                }
+            }
+            _ => out.push('$'),
+        }
+    }
+
+    Ok(out)
+}
+
+fn resolve_braced(expr: &str, vars: &HashMap<String, String>) -> Result<String, DockerPyo3Error> {
+    // A name is a prefix of name-start/name-char characters, so the
+    // operator (if any) begins at the first character that can't be part
+    // of the name - found by scanning the expression itself rather than
+    // `find`-ing each operator's literal text in the whole string, which
+    // misfires whenever the operand happens to contain those same
+    // characters (e.g. `${TAG:-1.0-rc1}` or `${MSG:?missing-file}`).
+    let name_end = expr
+        .char_indices()
+        .find(|&(i, c)| if i == 0 { !is_name_start(c) } else { !is_name_char(c) })
+        .map(|(i, _)| i)
+        .unwrap_or(expr.len());
+
+    if name_end == expr.len() {
+        validate_name(expr)?;
+        return match vars.get(expr) {
+            Some(value) => Ok(value.clone()),
+            None => {
+                eprintln!("Warning: variable '{expr}' is not set, substituting an empty string");
+                Ok(String::new())
+            }
+        };
+    }
+
+    let name = &expr[..name_end];
+    let rest = &expr[name_end..];
+    validate_name(name)?;
+
+    let (op, is_strict) = [(":-", true), (":?", true), (":+", true), ("-", false), ("?", false), ("+", false)]
+        .into_iter()
+        .find(|(op, _)| rest.starts_with(op))
+        .ok_or_else(|| DockerPyo3Error::InvalidParameter(format!("malformed variable expression: '${{{expr}}}'")))?;
+
+    let operand = &rest[op.len()..];
+    let value = vars.get(name);
+    let set_and_nonempty = value.is_some_and(|v| !v.is_empty());
+
+    Ok(match op {
+        ":-" | "-" => match value {
+            Some(v) if is_strict && v.is_empty() => operand.to_string(),
+            Some(v) if !is_strict || !v.is_empty() => v.clone(),
+            _ => operand.to_string(),
+        },
+        ":?" | "?" => {
+            if (is_strict && !set_and_nonempty) || (!is_strict && value.is_none()) {
+                return Err(DockerPyo3Error::Configuration(operand.to_string()));
+            }
+            value.cloned().unwrap_or_default()
+        }
+        ":+" | "+" => {
+            if (is_strict && set_and_nonempty) || (!is_strict && value.is_some()) {
+                operand.to_string()
+            } else {
+                String::new()
+            }
+        }
+        _ => unreachable!(),
+    })
+}
+
+fn validate_name(name: &str) -> Result<(), DockerPyo3Error> {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if is_name_start(c) => {}
+        _ => {
+            return Err(DockerPyo3Error::InvalidParameter(format!(
+                "malformed variable name: '{name}'"
+            )))
+        }
+    }
+    if chars.all(is_name_char) {
+        Ok(())
+    } else {
+        Err(DockerPyo3Error::InvalidParameter(format!(
+            "malformed variable name: '{name}'"
+        )))
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_escape_and_bare_and_braced() {
+        let v = vars(&[("NAME", "web")]);
+        assert_eq!(interpolate("$$NAME", &v).unwrap(), "$NAME");
+        assert_eq!(interpolate("$NAME", &v).unwrap(), "web");
+        assert_eq!(interpolate("${NAME}", &v).unwrap(), "web");
+        assert_eq!(interpolate("$MISSING", &v).unwrap(), "");
+    }
+
+    #[test]
+    fn test_default_operator() {
+        let v = vars(&[("SET", "x"), ("EMPTY", "")]);
+        assert_eq!(interpolate("${UNSET:-def}", &v).unwrap(), "def");
+        assert_eq!(interpolate("${UNSET-def}", &v).unwrap(), "def");
+        assert_eq!(interpolate("${EMPTY:-def}", &v).unwrap(), "def");
+        assert_eq!(interpolate("${EMPTY-def}", &v).unwrap(), "");
+        assert_eq!(interpolate("${SET:-def}", &v).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_error_operator() {
+        let v = vars(&[("SET", "x")]);
+        assert_eq!(interpolate("${SET:?missing}", &v).unwrap(), "x");
+        let err = interpolate("${UNSET:?required}", &v).unwrap_err();
+        assert!(matches!(err, DockerPyo3Error::Configuration(m) if m == "required"));
+    }
+
+    #[test]
+    fn test_alt_operator() {
+        let v = vars(&[("SET", "x"), ("EMPTY", "")]);
+        assert_eq!(interpolate("${SET:+alt}", &v).unwrap(), "alt");
+        assert_eq!(interpolate("${UNSET:+alt}", &v).unwrap(), "");
+        assert_eq!(interpolate("${EMPTY:+alt}", &v).unwrap(), "");
+        assert_eq!(interpolate("${EMPTY+alt}", &v).unwrap(), "alt");
+    }
+
+    #[test]
+    fn test_malformed_name_is_invalid_parameter() {
+        let v = vars(&[]);
+        assert!(matches!(interpolate("${1abc}", &v), Err(DockerPyo3Error::InvalidParameter(_))));
+        assert!(matches!(interpolate("${NAME", &v), Err(DockerPyo3Error::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_hyphenated_operand_does_not_confuse_operator_scan() {
+        let v = vars(&[]);
+        assert_eq!(interpolate("${TAG:-1.0-rc1}", &v).unwrap(), "1.0-rc1");
+        assert_eq!(interpolate("${TAG-1.0-rc1}", &v).unwrap(), "1.0-rc1");
+        assert_eq!(interpolate("${VAR+has-a-dash}", &v).unwrap(), "");
+
+        let set = vars(&[("VAR", "x")]);
+        assert_eq!(interpolate("${VAR+has-a-dash}", &set).unwrap(), "has-a-dash");
+        assert_eq!(interpolate("${VAR:+has-a-dash}", &set).unwrap(), "has-a-dash");
+
+        let err = interpolate("${MSG:?missing-file}", &v).unwrap_err();
+        assert!(matches!(err, DockerPyo3Error::Configuration(m) if m == "missing-file"));
+        let err = interpolate("${MSG?missing-file}", &v).unwrap_err();
+        assert!(matches!(err, DockerPyo3Error::Configuration(m) if m == "missing-file"));
+    }
+}
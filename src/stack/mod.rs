@@ -1,16 +1,12 @@
-// pub mod definition;
-// pub mod stack;  
-// pub mod service;
-
 pub mod simple_test;
 pub mod service_simple;
-
-// Temporarily disable complex modules while fixing compilation
-// pub use stack::Pyo3Stack;
-// pub use definition::StackDefinition;
-// pub use service::ServiceBuilder;
+pub mod interpolation;
+pub mod validation;
+pub mod image_ref;
+pub mod project;
 
 pub use service_simple::Service as InternalService;
+pub use project::Project as InternalProject;
 
 // Python wrapper for Service
 #[pyclass(name = "Service")]
@@ -68,11 +64,23 @@ impl Service {
         self.internal = self.internal.clone().network(network);
     }
 
+    /// Add multiple networks
+    pub fn networks(&mut self, networks: Vec<String>) {
+        self.internal = self.internal.clone().networks(networks);
+    }
+
     /// Add dependency
     pub fn depends_on_service(&mut self, service: String) {
         self.internal = self.internal.clone().depends_on_service(service);
     }
 
+    /// Add a dependency gated on a compose-style condition
+    /// (`service_started`, `service_healthy`, or
+    /// `service_completed_successfully`)
+    pub fn depends_on_service_with_condition(&mut self, service: String, condition: String) {
+        self.internal = self.internal.clone().depends_on_service_with_condition(service, condition);
+    }
+
     /// Set restart policy
     pub fn restart_policy(&mut self, policy: String) {
         self.internal = self.internal.clone().restart_policy(policy);
@@ -83,6 +91,11 @@ impl Service {
         self.internal = self.internal.clone().hostname(hostname);
     }
 
+    /// Set the user (and optionally group) the container's process runs as, e.g. "1000:1000"
+    pub fn user(&mut self, user: String) {
+        self.internal = self.internal.clone().user(user);
+    }
+
     /// Add label
     pub fn label(&mut self, key: String, value: String) {
         self.internal = self.internal.clone().label(key, value);
@@ -93,6 +106,28 @@ impl Service {
         self.internal = self.internal.clone().replicas(count);
     }
 
+    /// Set the service mode (`"replicated"` or `"global"`)
+    pub fn mode(&mut self, mode: String) {
+        self.internal = self.internal.clone().mode(mode);
+    }
+
+    /// Add a placement constraint (e.g. `"node.role==manager"`)
+    pub fn placement_constraint(&mut self, expr: String) {
+        self.internal = self.internal.clone().placement_constraint(expr);
+    }
+
+    /// Set the rolling update policy
+    #[pyo3(signature = (parallelism=None, delay=None, order=None))]
+    pub fn update_config(&mut self, parallelism: Option<u64>, delay: Option<String>, order: Option<String>) {
+        self.internal = self.internal.clone().update_config(parallelism, delay, order);
+    }
+
+    /// Set the rollback policy
+    #[pyo3(signature = (parallelism=None, delay=None, order=None))]
+    pub fn rollback_config(&mut self, parallelism: Option<u64>, delay: Option<String>, order: Option<String>) {
+        self.internal = self.internal.clone().rollback_config(parallelism, delay, order);
+    }
+
     /// Set memory limit
     pub fn memory(&mut self, limit: String) {
         self.internal = self.internal.clone().memory(limit);
@@ -136,6 +171,11 @@ impl Service {
     pub fn cpus(&mut self, cpus: String) {
         self.internal = self.internal.clone().cpus(cpus);
     }
+
+    /// Set reserved CPUs
+    pub fn cpu_reservation(&mut self, cpus: String) {
+        self.internal = self.internal.clone().cpu_reservation(cpus);
+    }
     
     /// Set CPU shares
     pub fn cpu_shares(&mut self, shares: u64) {
@@ -173,10 +213,49 @@ impl Service {
     pub fn secret(&mut self, secret: String) {
         self.internal = self.internal.clone().secret(secret);
     }
-    
+
+    /// Attach a secret mounted at `target` (or `/run/secrets/<name>` if
+    /// omitted) with the given ownership/mode
+    #[pyo3(signature = (name, target=None, uid=None, gid=None, mode=None))]
+    pub fn secret_advanced(
+        &mut self,
+        name: String,
+        target: Option<String>,
+        uid: Option<String>,
+        gid: Option<String>,
+        mode: Option<u32>,
+    ) {
+        self.internal = self.internal.clone().secret_advanced(name, target, uid, gid, mode);
+    }
+
+    /// Add config
+    pub fn config(&mut self, config: String) {
+        self.internal = self.internal.clone().config(config);
+    }
+
+    /// Attach a config mounted at `target` with the given ownership/mode
+    #[pyo3(signature = (name, target=None, uid=None, gid=None, mode=None))]
+    pub fn config_advanced(
+        &mut self,
+        name: String,
+        target: Option<String>,
+        uid: Option<String>,
+        gid: Option<String>,
+        mode: Option<u32>,
+    ) {
+        self.internal = self.internal.clone().config_advanced(name, target, uid, gid, mode);
+    }
+
     /// Add health check
-    pub fn healthcheck(&mut self, test: Vec<String>, interval: Option<String>, timeout: Option<String>, retries: Option<u32>, start_period: Option<String>) {
-        self.internal = self.internal.clone().healthcheck(test, interval, timeout, retries, start_period);
+    #[pyo3(signature = (test, interval=None, timeout=None, retries=None, start_period=None, start_interval=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn healthcheck(&mut self, test: Vec<String>, interval: Option<String>, timeout: Option<String>, retries: Option<u32>, start_period: Option<String>, start_interval: Option<String>) {
+        self.internal = self.internal.clone().healthcheck(test, interval, timeout, retries, start_period, start_interval);
+    }
+
+    /// Explicitly disable an image's inherited healthcheck
+    pub fn disable_healthcheck(&mut self) {
+        self.internal = self.internal.clone().disable_healthcheck();
     }
 
     /// Clone with new name
@@ -220,19 +299,110 @@ impl Service {
     }
 }
 
+/// Python wrapper for `Project`, a connection-free planning layer that
+/// resolves `depends_on` into a deterministic start order without
+/// requiring a `Docker`/`Stack` instance at all.
+#[pyclass(name = "Project")]
+#[derive(Debug, Clone)]
+pub struct Pyo3Project {
+    internal: InternalProject,
+}
+
+#[pymethods]
+impl Pyo3Project {
+    #[new]
+    pub fn new(name: String) -> Self {
+        Self {
+            internal: InternalProject::new(name),
+        }
+    }
+
+    #[getter]
+    pub fn name(&self) -> String {
+        self.internal.name().to_string()
+    }
+
+    /// Register a service, in the order it should be preferred when
+    /// multiple services become startable at once.
+    pub fn service(&mut self, service: &Service) {
+        self.internal = self.internal.clone().service(service.internal().clone());
+    }
+
+    /// Declare a top-level network, independent of any service that joins it.
+    pub fn network(&mut self, network: String) {
+        self.internal = self.internal.clone().network(network);
+    }
+
+    /// Declare a top-level named volume.
+    pub fn volume(&mut self, volume: String) {
+        self.internal = self.internal.clone().volume(volume);
+    }
+
+    /// Declare a top-level secret.
+    pub fn secret(&mut self, secret: String) {
+        self.internal = self.internal.clone().secret(secret);
+    }
+
+    pub fn networks(&self) -> Vec<String> {
+        self.internal.networks().to_vec()
+    }
+
+    pub fn volumes(&self) -> Vec<String> {
+        self.internal.volumes().to_vec()
+    }
+
+    pub fn secrets(&self) -> Vec<String> {
+        self.internal.secrets().to_vec()
+    }
+
+    /// Resolve the order services should be started in so every
+    /// `depends_on` target starts before its dependents.
+    pub fn start_order(&self) -> PyResult<Vec<String>> {
+        Ok(self.internal.start_order()?)
+    }
+}
+
 // Enhanced Stack class with service registration
+use chrono::{DateTime, Utc};
+use docker_api::opts::{LogsOpts, PullOpts};
+use docker_api::{Container, Docker, Images};
+use futures_util::stream::StreamExt;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
-use crate::Pyo3Docker;
-use std::collections::HashMap;
+use pyo3::types::{PyDateTime, PyDict};
+use crate::error::DockerPyo3Error;
+use crate::{get_runtime, Pyo3Docker};
+use std::collections::{HashMap, VecDeque};
+use std::io::IsTerminal;
+use std::time::Duration;
+
+/// ANSI colors cycled across services in `Stack.logs()` output, the same
+/// way `docker-compose logs` colors each service's lines distinctly.
+const LOG_TAG_COLORS: [&str; 6] = [
+    "\x1b[36m", "\x1b[33m", "\x1b[32m", "\x1b[35m", "\x1b[34m", "\x1b[31m",
+];
 
-// mod stack_impl;  // Temporarily disabled due to compilation issues
 // mod stack_simple;  // Moved implementations to pymethods block
 
+/// Label stamped on every container `up()` creates, holding a stable hash
+/// of the service config that produced it, so a later `up()` can tell
+/// whether the container is still up to date.
+const CONFIG_HASH_LABEL: &str = "docker_pyo3.config_hash";
+
+/// Label stamped on every container a stack creates, holding the stack's
+/// name, so `down(remove_orphans=True)` can find containers that belong
+/// to this stack even if they aren't (or are no longer) tracked in
+/// `self.state.containers`.
+const PROJECT_LABEL: &str = "docker_pyo3.project";
+
+/// Label stamped on every container a stack creates, holding the name of
+/// the service it was created for.
+const SERVICE_LABEL: &str = "docker_pyo3.service";
+
 #[derive(Debug, Clone, Default)]
 pub struct StackState {
     pub containers: HashMap<String, Vec<String>>,
     pub networks: HashMap<String, String>,
+    pub volumes: HashMap<String, String>,
     pub status: StackStatus,
 }
 
@@ -252,6 +422,45 @@ impl Default for StackStatus {
     }
 }
 
+/// Controls how `up()` reconciles a service's already-deployed containers
+/// against its current config hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvergenceStrategy {
+    /// Recreate only containers whose config hash no longer matches (default)
+    Changed,
+    /// Recreate every container regardless of its hash
+    Always,
+    /// Leave any already-deployed containers untouched
+    Never,
+}
+
+impl Default for ConvergenceStrategy {
+    fn default() -> Self {
+        ConvergenceStrategy::Changed
+    }
+}
+
+impl ConvergenceStrategy {
+    fn from_str(value: &str) -> PyResult<Self> {
+        match value {
+            "changed" => Ok(ConvergenceStrategy::Changed),
+            "always" => Ok(ConvergenceStrategy::Always),
+            "never" => Ok(ConvergenceStrategy::Never),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "invalid convergence strategy '{other}', expected 'changed', 'always', or 'never'"
+            ))),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ConvergenceStrategy::Changed => "changed",
+            ConvergenceStrategy::Always => "always",
+            ConvergenceStrategy::Never => "never",
+        }
+    }
+}
+
 #[pyclass(name = "Stack")]
 #[derive(Debug, Clone)]
 pub struct Pyo3Stack {
@@ -259,17 +468,29 @@ pub struct Pyo3Stack {
     name: String,
     registered_services: HashMap<String, InternalService>,
     state: StackState,
+    /// Top-level `volumes:` names declared by the compose file this stack
+    /// was imported from (empty for stacks built up programmatically).
+    declared_volumes: Vec<String>,
+    /// Top-level `networks:` names declared by the compose file this stack
+    /// was imported from, in addition to the stack's own default network.
+    declared_networks: Vec<String>,
+    /// How `up()` reconciles already-deployed containers against their
+    /// current config hash (default `Changed`).
+    convergence_strategy: ConvergenceStrategy,
 }
 
 #[pymethods]
 impl Pyo3Stack {
     #[new]
     pub fn new(docker: Pyo3Docker, name: String) -> Self {
-        Self { 
-            docker, 
+        Self {
+            docker,
             name,
             registered_services: HashMap::new(),
             state: StackState::default(),
+            declared_volumes: Vec::new(),
+            declared_networks: Vec::new(),
+            convergence_strategy: ConvergenceStrategy::default(),
         }
     }
 
@@ -277,6 +498,22 @@ impl Pyo3Stack {
     pub fn name(&self) -> String {
         self.name.clone()
     }
+
+    /// Get the current convergence strategy (`"changed"`, `"always"`, or `"never"`)
+    #[getter]
+    pub fn convergence_strategy(&self) -> &'static str {
+        self.convergence_strategy.as_str()
+    }
+
+    /// Set how `up()` reconciles already-deployed containers: `"changed"`
+    /// (default) recreates only containers whose config hash no longer
+    /// matches, `"always"` recreates every container, and `"never"` leaves
+    /// already-deployed containers untouched.
+    #[setter]
+    pub fn set_convergence_strategy(&mut self, strategy: &str) -> PyResult<()> {
+        self.convergence_strategy = ConvergenceStrategy::from_str(strategy)?;
+        Ok(())
+    }
     
     /// Register a pre-built service into this stack
     pub fn register_service(&mut self, service: Service) -> PyResult<()> {
@@ -313,56 +550,67 @@ impl Pyo3Stack {
         self.registered_services.contains_key(&service_name)
     }
     
-    /// Export all registered services to simplified YAML
-    pub fn to_yaml(&self) -> PyResult<String> {
+    /// Export all registered services to simplified YAML.
+    ///
+    /// When `interpolate` is true, `${VAR}`-style references in image,
+    /// environment, and volume values are expanded against the process
+    /// environment before being written out.
+    #[pyo3(signature = (interpolate=false))]
+    pub fn to_yaml(&self, interpolate: bool) -> PyResult<String> {
         use std::collections::HashMap;
-        
+
+        let vars = Self::interpolation_vars(None, None);
+
         let mut output = String::new();
         output.push_str("version: '3.8'\n");
         output.push_str("services:\n");
-        
+
         for (name, service) in &self.registered_services {
             output.push_str(&format!("  {}:\n", name));
-            
+
             let config = service.to_config_map();
-            
+
             if let Some(image) = config.get("image") {
+                let image = Self::maybe_interpolate(image, interpolate, &vars)?;
                 output.push_str(&format!("    image: {}\n", image));
             }
-            
+
             if let Some(ports) = config.get("ports") {
                 if !ports.is_empty() {
                     output.push_str("    ports:\n");
                     for port in ports.split(',') {
+                        let port = Self::maybe_interpolate(port, interpolate, &vars)?;
                         output.push_str(&format!("      - \"{}\"\n", port));
                     }
                 }
             }
-            
+
             if let Some(env) = config.get("environment") {
                 if !env.is_empty() {
                     output.push_str("    environment:\n");
                     for env_var in env.split(',') {
                         if let Some((key, value)) = env_var.split_once('=') {
+                            let value = Self::maybe_interpolate(value, interpolate, &vars)?;
                             output.push_str(&format!("      {}: {}\n", key, value));
                         }
                     }
                 }
             }
-            
+
             if let Some(volumes) = config.get("volumes") {
                 if !volumes.is_empty() {
                     output.push_str("    volumes:\n");
                     for volume in volumes.split(',') {
+                        let volume = Self::maybe_interpolate(volume, interpolate, &vars)?;
                         output.push_str(&format!("      - {}\n", volume));
                     }
                 }
             }
-            
+
             if let Some(restart) = config.get("restart") {
                 output.push_str(&format!("    restart: {}\n", restart));
             }
-            
+
             if let Some(depends_on) = config.get("depends_on") {
                 if !depends_on.is_empty() {
                     output.push_str("    depends_on:\n");
@@ -372,7 +620,7 @@ impl Pyo3Stack {
                 }
             }
         }
-        
+
         Ok(output)
     }
     
@@ -393,11 +641,43 @@ services:
     
     // Phase 2.0 Stack Deployment Methods
     
-    /// Deploy the entire stack (Phase 2.0)
-    pub fn up(&mut self) -> PyResult<()> {
+    /// Deploy the entire stack (Phase 2.0).
+    ///
+    /// Services are deployed in dependency "waves" (see
+    /// [`Self::deploy_waves`]): every service within a wave has no
+    /// unsatisfied `depends_on` edges to a later wave, so the services in
+    /// a wave are created concurrently, bounded by `max_parallel` (at
+    /// least 1). A failure deploying one service never aborts the rest -
+    /// every failure is collected and, once every wave has been attempted,
+    /// surfaced together in a single aggregated error.
+    ///
+    /// When `interpolate` is true, `${VAR}`-style references in each
+    /// service's image and environment values are expanded against the
+    /// process environment before containers are created.
+    ///
+    /// `condition_timeout_secs` bounds how long a service will wait for a
+    /// `depends_on` condition (e.g. `service_healthy`) on a dependency
+    /// deployed in an earlier wave; exceeding it fails that service (and,
+    /// in turn, the overall `up()`) with an error naming the dependency.
+    ///
+    /// With `check=True`, nothing is touched in Docker at all: state is
+    /// reconciled against reality (a read) and a plan dict is returned
+    /// describing what a real `up()` would do -
+    /// `{"networks_to_create": [...], "volumes_to_create": [...],
+    /// "services": {name: {"action", "keeping", "creating", "removing"}}}`
+    /// - with `self.state`/`self.state.status` left exactly as reconciled.
+    #[pyo3(signature = (interpolate=false, max_parallel=4, condition_timeout_secs=60, check=false))]
+    pub fn up(&mut self, py: Python<'_>, interpolate: bool, max_parallel: usize, condition_timeout_secs: u64, check: bool) -> PyResult<Py<PyAny>> {
+        self.reconcile_state(py)?;
+        let vars = Self::interpolation_vars(None, None);
+
+        if check {
+            return self.plan_up(py, interpolate, &vars);
+        }
+
         // Create default network
         let network_name = format!("{}_default", self.name);
-        
+
         // Try to create the network, ignore if it already exists
         let network_id = match self.docker.networks().create(
             &network_name,
@@ -413,135 +693,725 @@ services:
                 }
             }
         };
-        
+
         // Store network ID
         self.state.networks.insert("default".to_string(), network_id);
-        
-        // Deploy services
-        for (service_name, service) in &self.registered_services {
-            let config = service.to_config_map();
-            
-            // Get image or skip if build-only
-            let image = match config.get("image") {
-                Some(img) => img.clone(),
-                None => {
-                    eprintln!("Service {} has no image (build not implemented), skipping", service_name);
-                    continue;
+
+        // Create any additional top-level `networks:`/`volumes:` declared
+        // by the compose file this stack was imported from, namespaced
+        // under the stack name the same way the default network is.
+        for name in self.declared_networks.clone() {
+            if self.state.networks.contains_key(&name) {
+                continue;
+            }
+            let qualified = format!("{}_{}", self.name, name);
+            let network_id = match self.docker.networks().create(
+                &qualified,
+                None, None, None, None, None, None, None, None,
+            ) {
+                Ok(network) => network.id(),
+                Err(e) if e.to_string().contains("already exists") => qualified,
+                Err(e) => return Err(e),
+            };
+            self.state.networks.insert(name, network_id);
+        }
+
+        for name in self.declared_volumes.clone() {
+            if self.state.volumes.contains_key(&name) {
+                continue;
+            }
+            let qualified = format!("{}_{}", self.name, name);
+            Python::with_gil(|py| -> PyResult<()> {
+                match self.docker.volumes().create(py, Some(&qualified), None, None, None) {
+                    Ok(_) => Ok(()),
+                    Err(e) if e.to_string().contains("already exists") => Ok(()),
+                    Err(e) => Err(e),
+                }
+            })?;
+            self.state.volumes.insert(name, qualified);
+        }
+
+        let waves = self.deploy_waves()?;
+        let max_parallel = max_parallel.max(1);
+        let condition_timeout = Duration::from_secs(condition_timeout_secs);
+        let mut failures: Vec<String> = Vec::new();
+
+        for wave in &waves {
+            for chunk in wave.chunks(max_parallel) {
+                // Release the GIL for the duration of this chunk so the
+                // worker threads below can each reacquire it independently
+                // while their Docker calls are in flight.
+                let this: &Pyo3Stack = self;
+                let results: Vec<(String, PyResult<Vec<String>>)> = py.allow_threads(|| {
+                    std::thread::scope(|scope| {
+                        let handles: Vec<_> = chunk
+                            .iter()
+                            .map(|service_name| {
+                                scope.spawn(move || {
+                                    let result = this
+                                        .wait_for_depends_on(service_name, condition_timeout)
+                                        .and_then(|()| this.deploy_service(service_name, interpolate, &vars, &network_name));
+                                    (service_name.clone(), result)
+                                })
+                            })
+                            .collect();
+
+                        handles
+                            .into_iter()
+                            .map(|handle| handle.join().expect("stack deploy worker thread panicked"))
+                            .collect()
+                    })
+                });
+
+                // Merge results back into shared state under a single
+                // (implicit, since we're back on the main thread) lock.
+                for (service_name, result) in results {
+                    match result {
+                        Ok(container_ids) => {
+                            if container_ids.is_empty() {
+                                self.state.containers.remove(&service_name);
+                            } else {
+                                self.state.containers.insert(service_name, container_ids);
+                            }
+                        }
+                        Err(e) => failures.push(format!("{}: {}", service_name, e)),
+                    }
+                }
+
+                // Pick up a Ctrl-C (SIGINT) or other pending Python signal
+                // between chunks and roll back whatever this call has
+                // deployed so far, rather than leaving a half-up stack
+                // behind for the caller to clean up by hand.
+                if let Err(e) = py.check_signals() {
+                    let _ = self.down(py, max_parallel, false, false, false);
+                    self.state.status = StackStatus::Failed;
+                    return Err(e);
+                }
+            }
+        }
+
+        // Remove containers for services that were deregistered since the
+        // last deploy - they're no longer part of the desired state. This
+        // is best-effort cleanup and runs regardless of deploy failures.
+        let orphaned_services: Vec<String> = self.state.containers.keys()
+            .filter(|name| !self.registered_services.contains_key(*name))
+            .cloned()
+            .collect();
+        for service_name in orphaned_services {
+            if let Some(container_ids) = self.state.containers.remove(&service_name) {
+                for container_id in container_ids {
+                    let container = self.docker.containers().get(&container_id);
+                    let _ = container.stop(None);
+                    let _ = container.remove(Some(true), None);
                 }
+            }
+        }
+
+        if !failures.is_empty() {
+            // Entirely empty state.containers means not a single service
+            // came up - that's a full failure rather than a partial one.
+            self.state.status = if self.state.containers.is_empty() {
+                StackStatus::Failed
+            } else {
+                StackStatus::PartiallyRunning
             };
-            
-            // Create container with minimal configuration
-            let container_name = format!("{}_{}_1", self.name, service_name);
-            
-            // Use a simple container creation approach
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to deploy service(s): {}",
+                failures.join("; ")
+            )));
+        }
+
+        self.state.status = StackStatus::Running;
+        Ok(py.None())
+    }
+
+    /// Build the plan dict `up(check=True)` returns, by replicating
+    /// `deploy_service`'s convergence decision per service via
+    /// [`Self::plan_service`] - a read-only pass over already-reconciled
+    /// state, never calling anything that creates/stops/removes a
+    /// container, network, or volume.
+    fn plan_up(&self, py: Python<'_>, interpolate: bool, vars: &HashMap<String, String>) -> PyResult<Py<PyAny>> {
+        let mut networks_to_create = Vec::new();
+        if !self.state.networks.contains_key("default") {
+            networks_to_create.push("default".to_string());
+        }
+        for name in &self.declared_networks {
+            if !self.state.networks.contains_key(name) {
+                networks_to_create.push(name.clone());
+            }
+        }
+
+        let mut volumes_to_create = Vec::new();
+        for name in &self.declared_volumes {
+            if !self.state.volumes.contains_key(name) {
+                volumes_to_create.push(name.clone());
+            }
+        }
+
+        let plan = PyDict::new(py);
+        plan.set_item("networks_to_create", networks_to_create)?;
+        plan.set_item("volumes_to_create", volumes_to_create)?;
+
+        let services = PyDict::new(py);
+        for service_name in self.deploy_order()? {
+            let (action, keeping, creating, removing) = self.plan_service(&service_name, interpolate, vars)?;
+            let detail = PyDict::new(py);
+            detail.set_item("action", action)?;
+            detail.set_item("keeping", keeping)?;
+            detail.set_item("creating", creating)?;
+            detail.set_item("removing", removing)?;
+            services.set_item(service_name, detail)?;
+        }
+        plan.set_item("services", services)?;
+
+        Ok(plan.into())
+    }
+
+    /// Compute, without touching Docker, what `deploy_service` would do for
+    /// `service_name` given the currently reconciled state - mirrors its
+    /// per-container convergence decision but only counts containers kept,
+    /// created, and removed instead of acting on them. Returns
+    /// `(action, keeping, creating, removing)` where `action` is one of
+    /// `"skip"`, `"create"`, `"unchanged"`, `"recreate"`, or `"converge"`
+    /// (a mix of kept and replaced containers).
+    fn plan_service(
+        &self,
+        service_name: &str,
+        interpolate: bool,
+        vars: &HashMap<String, String>,
+    ) -> PyResult<(String, usize, usize, usize)> {
+        let service = &self.registered_services[service_name];
+        let config = service.to_config_map();
+        Self::check_unsupported_keys(&config)?;
+        let config_hash = Self::config_hash(&config);
+        let replicas = service.get_replicas().max(1);
+
+        let image = match config.get("image") {
+            Some(img) => img,
+            None => return Ok(("skip".to_string(), 0, 0, 0)),
+        };
+        Self::maybe_interpolate(image, interpolate, vars)?;
+
+        let existing_ids = self.state.containers.get(service_name).cloned().unwrap_or_default();
+
+        if existing_ids.is_empty() {
+            return Ok(("create".to_string(), 0, replicas as usize, 0));
+        }
+
+        if self.convergence_strategy == ConvergenceStrategy::Never {
+            return Ok(("unchanged".to_string(), existing_ids.len(), 0, 0));
+        }
+
+        let mut keeping = 0u32;
+        let mut removing = 0usize;
+        for container_id in &existing_ids {
+            let up_to_date = self.convergence_strategy == ConvergenceStrategy::Changed
+                && self.container_config_hash(container_id).as_deref() == Some(config_hash.as_str());
+
+            if up_to_date && keeping < replicas {
+                keeping += 1;
+            } else {
+                removing += 1;
+            }
+        }
+        let creating = (replicas - keeping) as usize;
+
+        let action = if removing == 0 && creating == 0 {
+            "unchanged"
+        } else if keeping == 0 {
+            "recreate"
+        } else {
+            "converge"
+        };
+
+        Ok((action.to_string(), keeping as usize, creating, removing))
+    }
+
+    /// Converge a single service's containers to its desired replica count
+    /// and config, returning the resulting container IDs, kept and newly
+    /// created alike (empty only if the service has no image and nothing
+    /// was created). Called concurrently from `up()`, so
+    /// this only reads shared state - all writes to `self.state` happen
+    /// back on the caller's thread once every worker has finished.
+    fn deploy_service(
+        &self,
+        service_name: &str,
+        interpolate: bool,
+        vars: &HashMap<String, String>,
+        network_name: &str,
+    ) -> PyResult<Vec<String>> {
+        let service = &self.registered_services[service_name];
+        let config = service.to_config_map();
+        Self::check_unsupported_keys(&config)?;
+        let config_hash = Self::config_hash(&config);
+        let replicas = service.get_replicas().max(1);
+
+        // Get image or skip if build-only
+        let image = match config.get("image") {
+            Some(img) => Self::maybe_interpolate(img, interpolate, vars)?,
+            None => {
+                eprintln!("Service {} has no image (build not implemented), skipping", service_name);
+                return Ok(Vec::new());
+            }
+        };
+
+        // Converge existing containers against `convergence_strategy`:
+        //   Never   - leave any existing containers alone entirely
+        //   Always  - recreate every container regardless of its hash
+        //   Changed - per-container diff: keep containers whose hash still
+        //             matches, replace the rest, and top up/trim to reach
+        //             `replicas`
+        let existing_ids = self.state.containers.get(service_name).cloned().unwrap_or_default();
+
+        if self.convergence_strategy == ConvergenceStrategy::Never && !existing_ids.is_empty() {
+            return Ok(existing_ids);
+        }
+
+        let mut kept_ids = Vec::new();
+        for container_id in &existing_ids {
+            let up_to_date = self.convergence_strategy == ConvergenceStrategy::Changed
+                && self.container_config_hash(container_id).as_deref() == Some(config_hash.as_str());
+
+            if up_to_date && (kept_ids.len() as u32) < replicas {
+                kept_ids.push(container_id.clone());
+            } else {
+                let container = self.docker.containers().get(container_id);
+                let _ = container.stop(None);
+                let _ = container.remove(Some(true), None);
+            }
+        }
+
+        if kept_ids.len() as u32 == replicas {
+            return Ok(kept_ids);
+        }
+
+        let to_create = replicas - kept_ids.len() as u32;
+        self.ensure_image_pulled(&image)?;
+        let mut container_ids = kept_ids;
+        container_ids.reserve(to_create as usize);
+        for replica_num in (container_ids.len() as u32 + 1)..=replicas {
+            let container_name = format!("{}_{}_{}", self.name, service_name, replica_num);
+
+            // Use the full resolved service config to create the container
             let container = Python::with_gil(|py| -> PyResult<_> {
+                let labels_dict = PyDict::new(py);
+                labels_dict.set_item(CONFIG_HASH_LABEL, &config_hash)?;
+                labels_dict.set_item(PROJECT_LABEL, &self.name)?;
+                labels_dict.set_item(SERVICE_LABEL, service_name)?;
+                for (key, value) in service.get_labels() {
+                    labels_dict.set_item(key, value)?;
+                }
+
                 // Create command list using raw command to preserve structure
                 let cmd_list = if let Some(raw_cmd) = service.get_raw_command() {
                     let cmd_str_refs: Vec<&str> = raw_cmd.iter().map(|s| s.as_str()).collect();
-                    let list = pyo3::types::PyList::new(py, &cmd_str_refs);
-                    Some(list)
+                    Some(pyo3::types::PyList::new(py, &cmd_str_refs))
                 } else {
                     None
                 };
-                
+
                 // Create minimal environment list
                 let env_list = if let Some(env_str) = config.get("environment") {
-                    let env_pairs: Vec<&str> = env_str.split(',').collect();
-                    let list = pyo3::types::PyList::new(py, &env_pairs);
-                    Some(list)
+                    let env_pairs = env_str
+                        .split(',')
+                        .map(|pair| Self::maybe_interpolate(pair, interpolate, vars))
+                        .collect::<PyResult<Vec<String>>>()?;
+                    let env_pairs: Vec<&str> = env_pairs.iter().map(String::as_str).collect();
+                    Some(pyo3::types::PyList::new(py, &env_pairs))
                 } else {
                     None
                 };
-                
+
+                // Translate "published:target" port strings into `expose`
+                // entries (fixed host mapping) or, for a bare container
+                // port, `publish` entries (daemon picks the host port)
+                let mut expose_dicts = Vec::new();
+                let mut publish_dicts = Vec::new();
+                for port in service.get_ports() {
+                    let port = Self::maybe_interpolate(port, interpolate, vars)?;
+                    if let Some((published, target)) = port.split_once(':') {
+                        if let (Ok(published), Ok(target)) = (published.parse::<u32>(), target.parse::<u32>()) {
+                            let dict = PyDict::new(py);
+                            dict.set_item("srcport", target)?;
+                            dict.set_item("hostport", published)?;
+                            expose_dicts.push(dict);
+                        }
+                    } else if let Ok(target) = port.parse::<u32>() {
+                        let dict = PyDict::new(py);
+                        dict.set_item("port", target)?;
+                        publish_dicts.push(dict);
+                    }
+                }
+                let expose_list = (!expose_dicts.is_empty()).then(|| pyo3::types::PyList::new(py, &expose_dicts));
+                let publish_list = (!publish_dicts.is_empty()).then(|| pyo3::types::PyList::new(py, &publish_dicts));
+
+                // Volume mounts are passed through as "source:target", with
+                // a source naming a declared volume resolved to its actual
+                // (stack-namespaced) Docker volume name
+                let volume_strings = service
+                    .get_volumes()
+                    .iter()
+                    .map(|v| Self::maybe_interpolate(v, interpolate, vars))
+                    .collect::<PyResult<Vec<String>>>()?
+                    .iter()
+                    .map(|v| self.resolve_volume_string(v))
+                    .collect::<Vec<String>>();
+                let volume_str_refs: Vec<&str> = volume_strings.iter().map(String::as_str).collect();
+                let volumes_list = (!volume_str_refs.is_empty()).then(|| pyo3::types::PyList::new(py, &volume_str_refs));
+
+                // Restart policy, e.g. "on-failure:5" or "always"
+                let restart_policy_dict = match service.get_restart_policy() {
+                    Some(policy) => {
+                        let dict = PyDict::new(py);
+                        let (name, max_retry) = match policy.split_once(':') {
+                            Some((name, count)) => (name, count.parse::<u64>().unwrap_or(0)),
+                            None => (policy, 0),
+                        };
+                        dict.set_item("name", name)?;
+                        dict.set_item("maximum_retry_count", max_retry)?;
+                        Some(dict)
+                    }
+                    None => None,
+                };
+
+                let resources = service.get_resources();
+                let memory = resources.memory.as_deref().and_then(Self::parse_memory_bytes);
+                let cpus = resources.cpus.as_deref().and_then(|c| c.parse::<f64>().ok());
+                let cpu_shares = resources.cpu_shares.and_then(|shares| u32::try_from(shares).ok());
+
                 // Call the create method with proper arguments
                 self.docker.containers().create(
-                    &image,          // image
-                    None,            // attach_stderr
-                    None,            // attach_stdin
-                    None,            // attach_stdout
-                    None,            // auto_remove
-                    None,            // capabilities
-                    cmd_list,        // command
-                    None,            // cpu_shares
-                    None,            // cpus
-                    None,            // devices
-                    None,            // entrypoint
-                    env_list,        // env
-                    None,            // expose
-                    None,            // extra_hosts
-                    None,            // labels
-                    None,            // links
-                    None,            // log_driver
-                    None,            // memory
-                    None,            // memory_swap
+                    &image,              // image
+                    None,                // attach_stderr
+                    None,                // attach_stdin
+                    None,                // attach_stdout
+                    None,                // auto_remove
+                    None,                // blkio_weight
+                    None,                // capabilities
+                    None,                // cap_drop
+                    cmd_list.as_ref(),   // command
+                    None,                // cpu_period
+                    None,                // cpu_quota
+                    cpu_shares,          // cpu_shares
+                    cpus,                // cpus
+                    None,                // devices
+                    None,                // dns
+                    None,                // dns_search
+                    None,                // entrypoint
+                    env_list.as_ref(),   // env
+                    expose_list.as_ref(), // expose
+                    None,                // extra_hosts
+                    Some(&labels_dict),  // labels
+                    None,                // links
+                    None,                // log_driver
+                    memory,              // memory
+                    None,                // memory_swap
                     Some(&container_name), // name
-                    None,            // nano_cpus
-                    None,            // network_mode
-                    None,            // privileged
-                    None,            // publish
-                    None,            // ports
-                    None,            // publish_all_ports
-                    None,            // restart_policy
-                    None,            // security_options
-                    None,            // stop_signal
-                    None,            // stop_signal_num
-                    None,            // stop_timeout
-                    None,            // tty
-                    None,            // user
-                    None,            // userns_mode
-                    None,            // volumes
-                    None,            // volumes_from
-                    config.get("working_dir").map(|s| s.as_str()) // working_dir
+                    None,                // nano_cpus
+                    Some(network_name),  // network_mode
+                    None,                // oom_kill_disable
+                    None,                // pids_limit
+                    None,                // privileged
+                    publish_list.as_ref(), // publish
+                    None,                // publish_all_ports
+                    None,                // readonly_rootfs
+                    restart_policy_dict.as_ref(), // restart_policy
+                    None,                // security_options
+                    None,                // shm_size
+                    None,                // stop_signal
+                    None,                // stop_signal_num
+                    None,                // stop_timeout
+                    None,                // tty
+                    config.get("user").map(String::as_str), // user
+                    None,                // userns_mode
+                    volumes_list.as_ref(), // volumes
+                    None,                // volumes_from
+                    config.get("working_dir").map(|s| s.as_str()), // working_dir
+                    None,                // resolve_host_paths
                 )
             })?;
-            
+
             // Start the container
             container.start()?;
-            
-            // Track container by getting its ID
+
             let container_id = container.id()?;
-            self.state.containers.entry(service_name.clone())
-                .or_insert_with(Vec::new)
-                .push(container_id);
+
+            // Attach to any additional networks this service declared
+            // beyond the stack's default one - a container can only be
+            // created with a single `network_mode`, so extras are joined
+            // with a follow-up connect.
+            for network_key in service.get_networks() {
+                if let Some(network_id) = self.state.networks.get(network_key) {
+                    self.connect_with_alias(network_id, &container_id, service_name);
+                }
+            }
+
+            container_ids.push(container_id);
         }
-        
-        self.state.status = StackStatus::Running;
+
+        Ok(container_ids)
+    }
+
+    /// Pull `image` if the daemon doesn't already have it locally, so
+    /// `deploy_service`'s `containers().create()` call below never fails
+    /// with a bare "no such image" - the way `docker-compose up` silently
+    /// pulls on first deploy. Best-effort: an existing local image is left
+    /// untouched (no implicit re-pull of `:latest`).
+    fn ensure_image_pulled(&self, image: &str) -> PyResult<()> {
+        if self.docker.images().get(image).inspect().is_ok() {
+            return Ok(());
+        }
+
+        let images: Images = self.docker.images();
+        let pull_opts = PullOpts::builder().image(image).build();
+
+        get_runtime()
+            .block_on(async {
+                let mut stream = images.pull(&pull_opts);
+                while let Some(chunk) = stream.next().await {
+                    chunk?;
+                }
+                Ok::<(), docker_api::Error>(())
+            })
+            .map_err(DockerPyo3Error::from)?;
         Ok(())
     }
-    
-    /// Stop and remove the entire stack (Phase 2.0)
-    pub fn down(&mut self) -> PyResult<()> {
-        // Remove containers
+
+    /// Stop and remove the entire stack (Phase 2.0).
+    ///
+    /// Services are torn down wave-by-wave in the reverse of deploy order,
+    /// so a service is always stopped before the dependencies it relies
+    /// on, with every service in a wave stopped and removed concurrently
+    /// (bounded by `max_parallel`). Teardown is best-effort throughout -
+    /// failures stopping or removing one service's containers never
+    /// prevent the rest of the stack from being torn down.
+    ///
+    /// With `remove_volumes`, named volumes created for this stack's
+    /// top-level `volumes:` declarations are removed too. With
+    /// `remove_orphans`, any container carrying this stack's project label
+    /// whose service is no longer registered is found and removed as well,
+    /// even if it isn't tracked in `self.state`.
+    ///
+    /// Returns a summary dict (`containers_removed`, `networks_removed`,
+    /// `volumes_removed`), mirroring the shape of `status()`.
+    ///
+    /// With `check=True`, nothing is touched in Docker: state is reconciled
+    /// against reality (a read) and the same-shaped dict reports what
+    /// *would* be removed, leaving `self.state`/`self.state.status` exactly
+    /// as reconciled.
+    #[pyo3(signature = (max_parallel=4, remove_volumes=false, remove_orphans=false, check=false))]
+    pub fn down(
+        &mut self,
+        py: Python<'_>,
+        max_parallel: usize,
+        remove_volumes: bool,
+        remove_orphans: bool,
+        check: bool,
+    ) -> PyResult<Py<PyAny>> {
+        self.reconcile_state(py)?;
+
+        if check {
+            return self.plan_down(py, remove_volumes, remove_orphans);
+        }
+
+        let mut waves = self.deploy_waves().unwrap_or_default();
+        waves.reverse();
+        let max_parallel = max_parallel.max(1);
+        let mut containers_removed = 0usize;
+
+        for wave in &waves {
+            for chunk in wave.chunks(max_parallel) {
+                let this: &Pyo3Stack = self;
+                let torn_down: Vec<(String, usize)> = py.allow_threads(|| {
+                    std::thread::scope(|scope| {
+                        let handles: Vec<_> = chunk
+                            .iter()
+                            .filter_map(|service_name| {
+                                let container_ids = this.state.containers.get(service_name)?.clone();
+                                Some(scope.spawn(move || {
+                                    for container_id in &container_ids {
+                                        let container = this.docker.containers().get(container_id);
+                                        let _ = container.stop(None);
+                                        let _ = container.remove(Some(true), None);
+                                    }
+                                    (service_name.clone(), container_ids.len())
+                                }))
+                            })
+                            .collect();
+
+                        handles
+                            .into_iter()
+                            .map(|handle| handle.join().expect("stack teardown worker thread panicked"))
+                            .collect()
+                    })
+                });
+
+                for (service_name, count) in torn_down {
+                    self.state.containers.remove(&service_name);
+                    containers_removed += count;
+                }
+            }
+        }
+
+        // Any containers left over (e.g. for services deregistered since
+        // the last `up()`) have no ordering constraint; clean them up last.
         for (_, container_ids) in self.state.containers.clone() {
             for container_id in container_ids {
                 let container = self.docker.containers().get(&container_id);
                 // Try to stop and remove (ignore errors for cleanup)
                 let _ = container.stop(None);
                 let _ = container.remove(Some(true), None);
+                containers_removed += 1;
             }
         }
         self.state.containers.clear();
-        
-        // Remove networks
-        let network_name = format!("{}_default", self.name);
-        
+
+        if remove_orphans {
+            containers_removed += self.remove_orphaned_containers(py)?;
+        }
+
+        // Remove networks
+        let network_name = format!("{}_default", self.name);
+        let mut networks_removed = 0usize;
+
         // Try to remove by stored ID first
         for (_, network_id) in self.state.networks.clone() {
             let network = self.docker.networks().get(&network_id);
-            let _ = network.delete();
+            if network.delete().is_ok() {
+                networks_removed += 1;
+            }
         }
-        
+
         // Also try to remove by name in case ID wasn't stored
         let network = self.docker.networks().get(&network_name);
         let _ = network.delete();
-        
+
         self.state.networks.clear();
-        
+
+        let mut volumes_removed = 0usize;
+        if remove_volumes {
+            for (_, volume_name) in self.state.volumes.clone() {
+                let volume = self.docker.volumes().get(&volume_name);
+                if volume.delete().is_ok() {
+                    volumes_removed += 1;
+                }
+            }
+            self.state.volumes.clear();
+        }
+
         self.state.status = StackStatus::NotDeployed;
-        Ok(())
+
+        Python::with_gil(|py| {
+            let summary = PyDict::new(py);
+            summary.set_item("containers_removed", containers_removed)?;
+            summary.set_item("networks_removed", networks_removed)?;
+            summary.set_item("volumes_removed", volumes_removed)?;
+            Ok(summary.into())
+        })
     }
-    
+
+    /// Find containers carrying this stack's project label whose service
+    /// is no longer registered, and remove them. Used by
+    /// `down(remove_orphans=True)` to clean up containers left behind by
+    /// services that were dropped from the stack definition (or created
+    /// by a previous, now-forgotten process) and so aren't tracked in
+    /// `self.state.containers`. Returns the number removed.
+    fn remove_orphaned_containers(&self, py: Python<'_>) -> PyResult<usize> {
+        let orphan_ids = self.find_orphaned_container_ids(py)?;
+
+        let mut removed = 0usize;
+        for id in orphan_ids {
+            let container = self.docker.containers().get(&id);
+            let _ = container.stop(None);
+            if container.remove(Some(true), None).is_ok() {
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// List the IDs of containers carrying this stack's project label whose
+    /// service is no longer registered - the read-only half of
+    /// `remove_orphaned_containers`, also used by `down(check=True)` to
+    /// report how many orphans *would* be removed.
+    fn find_orphaned_container_ids(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        let containers = self.docker.containers().list(py, Some(true), None, None, None);
+        let list = containers.extract::<&pyo3::types::PyList>(py)?;
+
+        let mut orphan_ids = Vec::new();
+        for item in list.iter() {
+            let Ok(item_dict) = item.extract::<&PyDict>() else { continue };
+            let Some(labels) = item_dict.get_item("Labels") else { continue };
+            let Ok(labels_dict) = labels.extract::<&PyDict>() else { continue };
+
+            let Some(project) = labels_dict.get_item(PROJECT_LABEL) else { continue };
+            if project.extract::<String>().ok().as_deref() != Some(self.name.as_str()) {
+                continue;
+            }
+
+            let service_name = labels_dict
+                .get_item(SERVICE_LABEL)
+                .and_then(|v| v.extract::<String>().ok());
+            if service_name.is_some_and(|name| self.registered_services.contains_key(&name)) {
+                continue;
+            }
+
+            if let Some(id) = item_dict.get_item("Id").and_then(|v| v.extract::<String>().ok()) {
+                orphan_ids.push(id);
+            }
+        }
+
+        Ok(orphan_ids)
+    }
+
+    /// Build the summary dict `down(check=True)` returns: the same shape
+    /// as a real `down()`'s result, but counting what would be removed
+    /// from already-reconciled state instead of calling any
+    /// container/network/volume removal.
+    fn plan_down(&self, py: Python<'_>, remove_volumes: bool, remove_orphans: bool) -> PyResult<Py<PyAny>> {
+        let mut containers_removed: usize = self.state.containers.values().map(Vec::len).sum();
+        if remove_orphans {
+            containers_removed += self.find_orphaned_container_ids(py)?.len();
+        }
+
+        let networks_removed = self.state.networks.len();
+        let volumes_removed = if remove_volumes { self.state.volumes.len() } else { 0 };
+
+        let summary = PyDict::new(py);
+        summary.set_item("containers_removed", containers_removed)?;
+        summary.set_item("networks_removed", networks_removed)?;
+        summary.set_item("volumes_removed", volumes_removed)?;
+        Ok(summary.into())
+    }
+
+    /// Declaratively converge the stack to `state` ("present" deploys it
+    /// via `up()`, "absent" tears it down via `down()`), the Ansible-style
+    /// state= convention some callers prefer over choosing up/down
+    /// themselves. `check` is forwarded as-is, so `apply(state="present",
+    /// check=True)` is exactly `up(check=True)`'s dry-run plan.
+    #[pyo3(signature = (state, max_parallel=4, check=false))]
+    pub fn apply(&mut self, py: Python<'_>, state: String, max_parallel: usize, check: bool) -> PyResult<Py<PyAny>> {
+        match state.as_str() {
+            "present" => self.up(py, false, max_parallel, 60, check),
+            "absent" => self.down(py, max_parallel, false, false, check),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown state '{}': expected \"present\" or \"absent\"", other
+            ))),
+        }
+    }
+
     /// Get stack status (Phase 2.0)
+    /// Run the Compose-semantic checks from [`validation::validate_stack_semantics`]
+    /// against the currently registered services, returning every violation
+    /// found (empty if the stack is valid) rather than raising on the first.
+    pub fn validate(&self) -> Vec<String> {
+        match validation::validate_stack_semantics(&self.registered_services, &self.declared_networks, &self.declared_volumes) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.into_iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
     pub fn status(&self) -> PyResult<Py<PyAny>> {
         Python::with_gil(|py| {
             let status_dict = PyDict::new(py);
@@ -559,12 +1429,22 @@ services:
             
             // Service statuses with container health
             let services_dict = PyDict::new(py);
-            for service_name in self.registered_services.keys() {
+            for (service_name, service) in &self.registered_services {
                 let service_dict = PyDict::new(py);
-                
+
                 if let Some(container_ids) = self.state.containers.get(service_name) {
                     service_dict.set_item("replicas", container_ids.len())?;
-                    
+
+                    // Whether the deployed container(s) still match this
+                    // service's current config, or need an `up()` to converge
+                    let current_hash = Self::config_hash(&service.to_config_map());
+                    let up_to_date = container_ids
+                        .first()
+                        .and_then(|id| self.container_config_hash(id))
+                        .as_deref()
+                        == Some(current_hash.as_str());
+                    service_dict.set_item("deploy_state", if up_to_date { "up_to_date" } else { "needs_recreate" })?;
+
                     // Check health status of each container
                     let mut running_count = 0;
                     let mut healthy_count = 0;
@@ -645,6 +1525,7 @@ services:
                     service_dict.set_item("containers", container_statuses)?;
                 } else {
                     service_dict.set_item("replicas", 0)?;
+                    service_dict.set_item("deploy_state", "needs_recreate")?;
                     service_dict.set_item("running", 0)?;
                     service_dict.set_item("healthy", 0)?;
                     service_dict.set_item("unhealthy", 0)?;
@@ -660,154 +1541,373 @@ services:
                 .map(|v| v.len()).sum();
             status_dict.set_item("total_containers", total_containers)?;
             status_dict.set_item("networks", self.state.networks.len())?;
-            
+            status_dict.set_item("volumes", self.state.volumes.len())?;
+
             Ok(status_dict.into())
         })
     }
-    
-    /// Get logs from services (Phase 2.0) 
-    pub fn logs(&self, services: Option<Vec<String>>) -> PyResult<String> {
-        let target_services = services.unwrap_or_else(|| 
-            self.registered_services.keys().cloned().collect()
-        );
-        
-        let mut all_logs = Vec::new();
-        
-        for service_name in target_services {
-            if let Some(container_ids) = self.state.containers.get(&service_name) {
-                for container_id in container_ids {
-                    let container = self.docker.containers().get(container_id);
-                    let logs = container.logs(
-                        Some(true),  // stdout
-                        Some(true),  // stderr
-                        Some(true),  // timestamps
-                        None,        // n_lines
-                        None,        // all
-                        None         // since
-                    );
-                    all_logs.push(format!("[{}] {}", service_name, logs));
+
+    /// Run the Compose-semantic checks from [`validation::validate_stack_semantics`]
+    /// against the currently registered services, returning every violation
+    /// found (empty if the stack is valid) rather than raising on the first.
+    pub fn validate(&self) -> Vec<String> {
+        match validation::validate_stack_semantics(&self.registered_services, &self.declared_networks, &self.declared_volumes) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.into_iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    /// Fetch logs from every container in the deployed stack (Phase 2.0),
+    /// mirroring `docker-compose logs`: each line is prefixed with the
+    /// owning container's `service_replica` tag (e.g. `web_1 | ...`),
+    /// colored per service when the process's standard output is a
+    /// terminal. `services`, if given, restricts output to that subset of
+    /// service names.
+    ///
+    /// When `follow` is false (the default), returns the full captured
+    /// output as a single string, limited to the last `tail` lines per
+    /// container when given (or to output since `since`, if given - the
+    /// two are mutually exclusive the way the Docker API itself treats
+    /// them). When `follow` is true, returns a `StackLogs` iterator that
+    /// multiplexes every tracked container's stream, yielding one
+    /// formatted line per `next()` call as new output appears; `tail`
+    /// then only seeds the backlog the first lines are drawn from before
+    /// following live.
+    ///
+    /// `color` forces ANSI coloring of the per-service tag on (`True`) or
+    /// off (`False`); left as `None`, it's colored only when this
+    /// process's standard output is a terminal, so piping to a non-TTY
+    /// sink (a file, `| cat`) gets plain, colorless output by default.
+    #[pyo3(signature = (services=None, follow=false, tail=None, since=None, timestamps=false, color=None))]
+    pub fn logs(
+        &self,
+        py: Python<'_>,
+        services: Option<Vec<String>>,
+        follow: bool,
+        tail: Option<usize>,
+        since: Option<&Bound<'_, PyDateTime>>,
+        timestamps: bool,
+        color: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let targets = self.log_targets(services.as_deref());
+        let use_color = color.unwrap_or_else(|| std::io::stdout().is_terminal());
+        let since: Option<DateTime<Utc>> = since.map(|dt| dt.extract()).transpose()?;
+
+        if follow {
+            let stream = Pyo3StackLogs {
+                docker: self.docker.0.clone(),
+                targets,
+                timestamps,
+                tail,
+                since,
+                use_color,
+                seen_lines: HashMap::new(),
+                pending: VecDeque::new(),
+                stopped: false,
+            };
+            return Ok(Py::new(py, stream)?.into_any());
+        }
+
+        let all = tail.is_none() && since.is_none();
+        let mut lines = Vec::new();
+        for (tag, container_id, color_index) in &targets {
+            let raw = py.allow_threads(|| {
+                Self::fetch_container_logs(&self.docker.0, container_id, timestamps, tail, all, since)
+            });
+            lines.extend(Self::tag_log_block(&raw, tag, *color_index, use_color));
+        }
+
+        Ok(lines.join("\n").into_py(py))
+    }
+
+    /// Resolve `(tag, container_id, color_index)` triples for `logs()`:
+    /// one entry per tracked container, tagged `service_replica` (e.g.
+    /// `web_1`) matching the container names `up()` assigns, restricted to
+    /// `services` when given. `color_index` is stable per service (shared
+    /// across its replicas) and ordered by service name for determinism.
+    fn log_targets(&self, services: Option<&[String]>) -> Vec<(String, String, usize)> {
+        let mut service_names: Vec<&String> = match services {
+            Some(names) => names.iter().collect(),
+            None => self.state.containers.keys().collect(),
+        };
+        service_names.sort_unstable();
+        service_names.dedup();
+
+        let mut targets = Vec::new();
+        for (color_index, service_name) in service_names.iter().enumerate() {
+            if let Some(container_ids) = self.state.containers.get(*service_name) {
+                for (replica_index, container_id) in container_ids.iter().enumerate() {
+                    targets.push((
+                        format!("{}_{}", service_name, replica_index + 1),
+                        container_id.clone(),
+                        color_index,
+                    ));
                 }
             }
         }
-        
-        Ok(all_logs.join("\n"))
+        targets
     }
-    
-    /// Helper function to create a container for a service
-    fn create_service_container(&mut self, service_name: &str, replica_num: u32) -> PyResult<()> {
+
+    /// Prefix every line of a captured log block with its container's tag.
+    fn tag_log_block(raw: &str, tag: &str, color_index: usize, use_color: bool) -> Vec<String> {
+        raw.lines()
+            .map(|line| Self::tag_line(line, tag, color_index, use_color))
+            .collect()
+    }
+
+    /// Prefix a single log line with its container's tag, colored per
+    /// service when `use_color` is set.
+    fn tag_line(line: &str, tag: &str, color_index: usize, use_color: bool) -> String {
+        if use_color {
+            let color = LOG_TAG_COLORS[color_index % LOG_TAG_COLORS.len()];
+            format!("{color}{tag}\x1b[0m | {line}")
+        } else {
+            format!("{tag} | {line}")
+        }
+    }
+
+    /// Create a single container for `service_name`'s given replica
+    /// number, using the stack's fully-wired service config (ports,
+    /// volumes, labels, resources, restart policy, etc.) the same way
+    /// `up()`'s `deploy_service` does, attaching it to the stack's default
+    /// network if one is deployed. Returns the new container's ID without
+    /// touching `self.state` - callers merge it in themselves, which lets
+    /// `scale()` create several replicas concurrently and merge under a
+    /// single lock afterwards.
+    fn create_service_container(&self, service_name: &str, replica_num: u32) -> PyResult<String> {
         let service = self.registered_services.get(service_name)
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
                 format!("Service '{}' not found", service_name)
             ))?;
-            
+
         let config = service.to_config_map();
-        
-        // Get image or return error
+        Self::check_unsupported_keys(&config)?;
+        let config_hash = Self::config_hash(&config);
+
         let image = config.get("image")
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
                 format!("Service '{}' has no image", service_name)
             ))?;
-            
-        // Create container name with replica number
-        let container_name = format!("{}_{}_{}",  self.name, service_name, replica_num);
-        
-        // Create container using same logic as up() method
+
+        let network_name = self.state.networks.get("default").cloned();
+        let container_name = format!("{}_{}_{}", self.name, service_name, replica_num);
+
         let container = Python::with_gil(|py| -> PyResult<_> {
-            // Create command list using raw command to preserve structure
+            let labels_dict = PyDict::new(py);
+            labels_dict.set_item(CONFIG_HASH_LABEL, &config_hash)?;
+            labels_dict.set_item(PROJECT_LABEL, &self.name)?;
+            labels_dict.set_item(SERVICE_LABEL, service_name)?;
+            for (key, value) in service.get_labels() {
+                labels_dict.set_item(key, value)?;
+            }
+
             let cmd_list = if let Some(raw_cmd) = service.get_raw_command() {
                 let cmd_str_refs: Vec<&str> = raw_cmd.iter().map(|s| s.as_str()).collect();
-                let list = pyo3::types::PyList::new(py, &cmd_str_refs);
-                Some(list)
+                Some(pyo3::types::PyList::new(py, &cmd_str_refs))
             } else {
                 None
             };
-            
-            // Create minimal environment list
+
             let env_list = if let Some(env_str) = config.get("environment") {
                 let env_pairs: Vec<&str> = env_str.split(',').collect();
-                let list = pyo3::types::PyList::new(py, &env_pairs);
-                Some(list)
+                Some(pyo3::types::PyList::new(py, &env_pairs))
             } else {
                 None
             };
-            
-            // Call the create method with proper arguments
+
+            let mut expose_dicts = Vec::new();
+            let mut publish_dicts = Vec::new();
+            for port in service.get_ports() {
+                if let Some((published, target)) = port.split_once(':') {
+                    if let (Ok(published), Ok(target)) = (published.parse::<u32>(), target.parse::<u32>()) {
+                        let dict = PyDict::new(py);
+                        dict.set_item("srcport", target)?;
+                        dict.set_item("hostport", published)?;
+                        expose_dicts.push(dict);
+                    }
+                } else if let Ok(target) = port.parse::<u32>() {
+                    let dict = PyDict::new(py);
+                    dict.set_item("port", target)?;
+                    publish_dicts.push(dict);
+                }
+            }
+            let expose_list = (!expose_dicts.is_empty()).then(|| pyo3::types::PyList::new(py, &expose_dicts));
+            let publish_list = (!publish_dicts.is_empty()).then(|| pyo3::types::PyList::new(py, &publish_dicts));
+
+            let volume_strings: Vec<String> = service
+                .get_volumes()
+                .iter()
+                .map(|v| self.resolve_volume_string(v))
+                .collect();
+            let volume_str_refs: Vec<&str> = volume_strings.iter().map(String::as_str).collect();
+            let volumes_list = (!volume_str_refs.is_empty()).then(|| pyo3::types::PyList::new(py, &volume_str_refs));
+
+            let restart_policy_dict = match service.get_restart_policy() {
+                Some(policy) => {
+                    let dict = PyDict::new(py);
+                    let (name, max_retry) = match policy.split_once(':') {
+                        Some((name, count)) => (name, count.parse::<u64>().unwrap_or(0)),
+                        None => (policy, 0),
+                    };
+                    dict.set_item("name", name)?;
+                    dict.set_item("maximum_retry_count", max_retry)?;
+                    Some(dict)
+                }
+                None => None,
+            };
+
+            let resources = service.get_resources();
+            let memory = resources.memory.as_deref().and_then(Self::parse_memory_bytes);
+            let cpus = resources.cpus.as_deref().and_then(|c| c.parse::<f64>().ok());
+            let cpu_shares = resources.cpu_shares.and_then(|shares| u32::try_from(shares).ok());
+
             self.docker.containers().create(
-                image,          // image
-                None,            // attach_stderr
-                None,            // attach_stdin
-                None,            // attach_stdout
-                None,            // auto_remove
-                None,            // capabilities
-                cmd_list,        // command
-                None,            // cpu_shares
-                None,            // cpus
-                None,            // devices
-                None,            // entrypoint
-                env_list,        // env
-                None,            // expose
-                None,            // extra_hosts
-                None,            // labels
-                None,            // links
-                None,            // log_driver
-                None,            // memory
-                None,            // memory_swap
+                image,               // image
+                None,                // attach_stderr
+                None,                // attach_stdin
+                None,                // attach_stdout
+                None,                // auto_remove
+                None,                // blkio_weight
+                None,                // capabilities
+                None,                // cap_drop
+                cmd_list.as_ref(),   // command
+                None,                // cpu_period
+                None,                // cpu_quota
+                cpu_shares,          // cpu_shares
+                cpus,                // cpus
+                None,                // devices
+                None,                // dns
+                None,                // dns_search
+                None,                // entrypoint
+                env_list.as_ref(),   // env
+                expose_list.as_ref(), // expose
+                None,                // extra_hosts
+                Some(&labels_dict),  // labels
+                None,                // links
+                None,                // log_driver
+                memory,              // memory
+                None,                // memory_swap
                 Some(&container_name), // name
-                None,            // nano_cpus
-                None,            // network_mode
-                None,            // privileged
-                None,            // publish
-                None,            // ports
-                None,            // publish_all_ports
-                None,            // restart_policy
-                None,            // security_options
-                None,            // stop_signal
-                None,            // stop_signal_num
-                None,            // stop_timeout
-                None,            // tty
-                None,            // user
-                None,            // userns_mode
-                None,            // volumes
-                None,            // volumes_from
-                config.get("working_dir").map(|s| s.as_str()) // working_dir
+                None,                // nano_cpus
+                network_name.as_deref(), // network_mode
+                None,                // oom_kill_disable
+                None,                // pids_limit
+                None,                // privileged
+                publish_list.as_ref(), // publish
+                None,                // publish_all_ports
+                None,                // readonly_rootfs
+                restart_policy_dict.as_ref(), // restart_policy
+                None,                // security_options
+                None,                // shm_size
+                None,                // stop_signal
+                None,                // stop_signal_num
+                None,                // stop_timeout
+                None,                // tty
+                config.get("user").map(String::as_str), // user
+                None,                // userns_mode
+                volumes_list.as_ref(), // volumes
+                None,                // volumes_from
+                config.get("working_dir").map(|s| s.as_str()), // working_dir
+                None,                // resolve_host_paths
             )
         })?;
-        
-        // Start the container
+
         container.start()?;
-        
-        // Track container by getting its ID
         let container_id = container.id()?;
-        self.state.containers.entry(service_name.to_string())
-            .or_insert_with(Vec::new)
-            .push(container_id);
-            
-        Ok(())
+
+        for network_key in service.get_networks() {
+            if let Some(network_id) = self.state.networks.get(network_key) {
+                self.connect_with_alias(network_id, &container_id, service_name);
+            }
+        }
+
+        Ok(container_id)
     }
 
-    /// Scale a service (Phase 2.0)
-    pub fn scale(&mut self, service_name: String, replicas: u32) -> PyResult<()> {
+    /// Scale a service (Phase 2.0) up or down to `replicas`.
+    ///
+    /// Replicas being added have no ordering constraint between them (only
+    /// across services, which `up()`'s dependency waves already handle),
+    /// so they're created concurrently - mirroring the same
+    /// GIL-released-per-worker-thread pattern `up()` uses - and merged
+    /// into `state.containers` together once every worker has finished.
+    ///
+    /// With `check=True`, nothing is touched in Docker: state is reconciled
+    /// against reality (a read) and a dict
+    /// (`{"action", "current", "target"}`) reports what scaling would do,
+    /// leaving `self.state` exactly as reconciled.
+    #[pyo3(signature = (service_name, replicas, check=false))]
+    pub fn scale(&mut self, py: Python<'_>, service_name: String, replicas: u32, check: bool) -> PyResult<Py<PyAny>> {
+        self.reconcile_state(py)?;
+
         if !self.registered_services.contains_key(&service_name) {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 format!("Service '{}' not found in stack", service_name)
             ));
         }
-        
+
         let current_containers = self.state.containers
             .get(&service_name)
             .map(|v| v.len())
             .unwrap_or(0) as u32;
-            
+
+        if check {
+            let action = match replicas.cmp(&current_containers) {
+                std::cmp::Ordering::Greater => "scale_up",
+                std::cmp::Ordering::Less => "scale_down",
+                std::cmp::Ordering::Equal => "unchanged",
+            };
+            let plan = PyDict::new(py);
+            plan.set_item("action", action)?;
+            plan.set_item("current", current_containers)?;
+            plan.set_item("target", replicas)?;
+            return Ok(plan.into());
+        }
+
         if replicas == current_containers {
-            return Ok(()); // Already at target replica count
+            return Ok(py.None()); // Already at target replica count
         }
-        
+
         if replicas > current_containers {
-            // Scale up - create additional containers
+            // Scale up - create the additional containers concurrently
             let containers_to_add = replicas - current_containers;
-            for i in 0..containers_to_add {
-                let replica_num = current_containers + i + 1;
-                self.create_service_container(&service_name, replica_num)?;
+            let this: &Pyo3Stack = self;
+            let service_name_ref: &str = &service_name;
+            let results: Vec<PyResult<String>> = py.allow_threads(|| {
+                std::thread::scope(|scope| {
+                    let handles: Vec<_> = (0..containers_to_add)
+                        .map(|i| {
+                            let replica_num = current_containers + i + 1;
+                            scope.spawn(move || this.create_service_container(service_name_ref, replica_num))
+                        })
+                        .collect();
+
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().expect("scale worker thread panicked"))
+                        .collect()
+                })
+            });
+
+            let mut failures = Vec::new();
+            let mut container_ids = Vec::new();
+            for result in results {
+                match result {
+                    Ok(container_id) => container_ids.push(container_id),
+                    Err(e) => failures.push(e.to_string()),
+                }
+            }
+
+            if !container_ids.is_empty() {
+                self.state.containers.entry(service_name.clone())
+                    .or_insert_with(Vec::new)
+                    .extend(container_ids);
+            }
+
+            if !failures.is_empty() {
+                return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "Failed to scale up service '{}': {}", service_name, failures.join("; ")
+                )));
             }
         } else {
             // Scale down - remove excess containers
@@ -822,52 +1922,801 @@ services:
                 }
             }
         }
-        
-        Ok(())
+
+        Ok(py.None())
     }
-    
+
     /// Restart a service (Phase 2.0)
-    pub fn restart_service(&mut self, service_name: String) -> PyResult<()> {
-        if !self.registered_services.contains_key(&service_name) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                format!("Service '{}' not found in stack", service_name)
-            ));
+    ///
+    /// Every one of the service's containers is stopped gracefully -
+    /// signaled with `stop_signal` (the override given here, falling back
+    /// to the service's own `stop_signal`, or `SIGTERM` otherwise) and given up to `timeout_secs` to exit before being
+    /// force-killed with `SIGKILL` - then started again. Restarting never
+    /// recreates a container, so its tracked ID is unchanged; an error
+    /// identifies any container that failed to come back up.
+    #[pyo3(signature = (service_name, timeout_secs=10, stop_signal=None))]
+    pub fn restart_service(
+        &self,
+        service_name: String,
+        timeout_secs: u64,
+        stop_signal: Option<String>,
+    ) -> PyResult<()> {
+        let service = self.registered_services.get(&service_name).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("Service '{}' not found in stack", service_name))
+        })?;
+        let signal = stop_signal.or_else(|| service.get_stop_signal().map(str::to_string));
+        let timeout = Duration::from_secs(timeout_secs);
+
+        let container_ids = self.state.containers.get(&service_name).cloned().unwrap_or_default();
+        let mut failures = Vec::new();
+
+        for container_id in &container_ids {
+            let container = self.docker.containers().get(container_id);
+
+            if container.kill(Some(signal.as_deref().unwrap_or("SIGTERM"))).is_ok() {
+                let deadline = std::time::Instant::now() + timeout;
+                loop {
+                    let still_running = container
+                        .inspect()
+                        .ok()
+                        .and_then(|info| {
+                            Python::with_gil(|py| -> Option<bool> {
+                                let info_dict = info.extract::<&PyDict>(py).ok()?;
+                                let state = info_dict.get_item("State")?;
+                                let state_dict = state.extract::<&PyDict>().ok()?;
+                                state_dict.get_item("Running")?.extract().ok()
+                            })
+                        })
+                        .unwrap_or(false);
+
+                    if !still_running {
+                        break;
+                    }
+                    if std::time::Instant::now() >= deadline {
+                        let _ = container.kill(Some("SIGKILL"));
+                        break;
+                    }
+                    std::thread::sleep(Duration::from_millis(250));
+                }
+            }
+
+            if let Err(e) = container.start() {
+                failures.push(format!("{}: {}", container_id, e));
+            }
         }
-        
-        // For now, just return Ok - full implementation would restart containers
+
+        if !failures.is_empty() {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to restart service '{}': {}",
+                service_name,
+                failures.join("; ")
+            )));
+        }
+
         Ok(())
     }
-    
+
+    /// Block until every container of `service_name` reports healthy (or,
+    /// for containers with no healthcheck, until they're simply running),
+    /// or `timeout_secs` elapses. Returns whether it became healthy in
+    /// time - useful for gating some external action on a service actually
+    /// being ready, the same way `wait_for_depends_on` gates a dependent
+    /// service's own deploy.
+    pub fn wait_healthy(&self, service_name: String, timeout_secs: u64) -> PyResult<bool> {
+        if !self.registered_services.contains_key(&service_name) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Service '{}' not found in stack", service_name
+            )));
+        }
+        let container_ids = self.state.containers.get(&service_name).cloned().unwrap_or_default();
+        if container_ids.is_empty() {
+            return Ok(false);
+        }
+
+        let timeout = Duration::from_secs(timeout_secs);
+        Ok(container_ids
+            .iter()
+            .all(|id| self.container_satisfies_condition(id, "service_healthy", timeout)))
+    }
+
     // Docker Compose Import Methods
     
-    /// Create a stack from a docker-compose.yml file
+    /// Create a stack from a docker-compose.yml file.
+    ///
+    /// `${VAR}`-style references are interpolated before parsing, resolved
+    /// from `env` first, then the process environment, then a sibling
+    /// `.env` file next to `file_path`.
     #[staticmethod]
-    pub fn from_file(docker: Pyo3Docker, file_path: String) -> PyResult<Pyo3Stack> {
+    #[pyo3(signature = (docker, file_path, env=None))]
+    pub fn from_file(docker: Pyo3Docker, file_path: String, env: Option<HashMap<String, String>>) -> PyResult<Pyo3Stack> {
         use std::fs;
-        
-        // Read the file
+
         let yaml_content = fs::read_to_string(&file_path)
             .map_err(|e| pyo3::exceptions::PyIOError::new_err(
                 format!("Failed to read docker-compose file '{}': {}", file_path, e)
             ))?;
-        
-        // Parse and create stack
-        Self::from_yaml(docker, yaml_content)
+
+        let dot_env_dir = std::path::Path::new(&file_path).parent();
+        let vars = Self::interpolation_vars(dot_env_dir, env);
+        let interpolated = interpolation::interpolate(&yaml_content, &vars)?;
+
+        Self::build_from_interpolated_yaml(docker, interpolated)
     }
-    
-    /// Create a stack from docker-compose YAML content
-    #[staticmethod] 
-    pub fn from_yaml(docker: Pyo3Docker, yaml_content: String) -> PyResult<Pyo3Stack> {
-        // Parse the docker-compose YAML
+
+    /// Create a stack from a docker-compose file path or raw YAML content.
+    ///
+    /// If `path_or_string` names an existing file, it's read and
+    /// interpolated the same way as `from_file` (with a sibling `.env`
+    /// taken into account). Otherwise it's treated as compose YAML content
+    /// directly and interpolated from `env` and the process environment.
+    #[staticmethod]
+    #[pyo3(signature = (docker, path_or_string, env=None))]
+    pub fn from_yaml(docker: Pyo3Docker, path_or_string: String, env: Option<HashMap<String, String>>) -> PyResult<Pyo3Stack> {
+        if std::path::Path::new(&path_or_string).is_file() {
+            return Self::from_file(docker, path_or_string, env);
+        }
+
+        let vars = Self::interpolation_vars(None, env);
+        let interpolated = interpolation::interpolate(&path_or_string, &vars)?;
+
+        Self::build_from_interpolated_yaml(docker, interpolated)
+    }
+
+    /// Create a stack from multiple docker-compose files, merged in order -
+    /// the `files:` override-and-merge behavior behind the common base +
+    /// override (`docker-compose.yml` + `docker-compose.override.yml`)
+    /// workflow. Each file is interpolated independently (against `env`,
+    /// a `.env` sibling of the *first* file, and the process environment)
+    /// before being deep-merged into the running document: mapping keys
+    /// from a later file win over an earlier one (recursing into nested
+    /// mappings rather than replacing them wholesale, so e.g. one file's
+    /// `services.web.environment` only needs to name the keys it
+    /// overrides), sequences are concatenated, and scalars are replaced.
+    /// `from_file` remains the single-file special case.
+    #[staticmethod]
+    #[pyo3(signature = (docker, file_paths, env=None))]
+    pub fn from_files(docker: Pyo3Docker, file_paths: Vec<String>, env: Option<HashMap<String, String>>) -> PyResult<Pyo3Stack> {
+        use std::fs;
+
+        let Some(first_path) = file_paths.first() else {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "from_files requires at least one file path"
+            ));
+        };
+
+        let dot_env_dir = std::path::Path::new(first_path).parent();
+        let vars = Self::interpolation_vars(dot_env_dir, env);
+
+        let mut merged: Option<serde_yaml::Value> = None;
+        for file_path in &file_paths {
+            let yaml_content = fs::read_to_string(file_path)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(
+                    format!("Failed to read docker-compose file '{}': {}", file_path, e)
+                ))?;
+            let interpolated = interpolation::interpolate(&yaml_content, &vars)?;
+            let value: serde_yaml::Value = serde_yaml::from_str(&interpolated)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(
+                    format!("Failed to parse docker-compose file '{}': {}", file_path, e)
+                ))?;
+            merged = Some(match merged {
+                Some(base) => Self::merge_yaml(base, value),
+                None => value,
+            });
+        }
+
+        let compose: docker_compose_types::Compose = serde_yaml::from_value(merged.expect("checked non-empty above"))
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(
+                format!("Failed to parse merged docker-compose document: {}", e)
+            ))?;
+
+        Self::build_from_compose(docker, compose)
+    }
+
+    /// Deep-merge two parsed compose documents the way multi-file `-f`
+    /// resolution does: mapping keys from `overlay` win over `base`,
+    /// recursing into nested mappings rather than replacing them wholesale;
+    /// sequences (e.g. a service's `ports`/`volumes`) are concatenated and
+    /// then deduplicated, keeping the first occurrence of each entry;
+    /// anything else in `overlay` simply replaces the value in `base`.
+    fn merge_yaml(base: serde_yaml::Value, overlay: serde_yaml::Value) -> serde_yaml::Value {
+        match (base, overlay) {
+            (serde_yaml::Value::Mapping(mut base_map), serde_yaml::Value::Mapping(overlay_map)) => {
+                for (key, overlay_value) in overlay_map {
+                    let merged_value = match base_map.remove(&key) {
+                        Some(base_value) => Self::merge_yaml(base_value, overlay_value),
+                        None => overlay_value,
+                    };
+                    base_map.insert(key, merged_value);
+                }
+                serde_yaml::Value::Mapping(base_map)
+            }
+            (serde_yaml::Value::Sequence(mut base_seq), serde_yaml::Value::Sequence(overlay_seq)) => {
+                base_seq.extend(overlay_seq);
+
+                let mut seen = Vec::with_capacity(base_seq.len());
+                base_seq.retain(|item| {
+                    if seen.contains(item) {
+                        false
+                    } else {
+                        seen.push(item.clone());
+                        true
+                    }
+                });
+
+                serde_yaml::Value::Sequence(base_seq)
+            }
+            (_, overlay) => overlay,
+        }
+    }
+
+    /// Reattach to an already-running stack by its project name.
+    ///
+    /// The returned `Stack` has no registered services - call
+    /// `.register_service()` to redeclare them if you need to `up()`/`scale()`
+    /// it further - but `state.containers/networks/volumes` is rebuilt from
+    /// whatever is already running in Docker, so `status()`, `down()` and
+    /// `logs()` work immediately against the real cluster instead of an
+    /// empty in-memory map.
+    #[staticmethod]
+    pub fn attach(docker: Pyo3Docker, name: String, py: Python<'_>) -> PyResult<Pyo3Stack> {
+        let mut stack = Self::new(docker, name);
+        stack.reconcile_state(py)?;
+        Ok(stack)
+    }
+
+    /// Rebuild `self.state` from whatever this project's resources already
+    /// look like in Docker, rather than trusting the in-memory map - used
+    /// by `attach()` on a fresh `Stack`, and by `up()`/`down()`/`scale()`
+    /// so they target the real cluster even after a process restart.
+    ///
+    /// Containers are grouped by their `SERVICE_LABEL`/`PROJECT_LABEL`
+    /// labels (stamped by `deploy_service`/`create_service_container`).
+    /// Networks and volumes aren't labeled, so they're matched by the
+    /// `"{project}_{name}"` naming scheme `up()` already creates them
+    /// under (`"default"` for the stack's own network).
+    fn reconcile_state(&mut self, py: Python<'_>) -> PyResult<()> {
+        let mut containers: HashMap<String, Vec<String>> = HashMap::new();
+        let raw_containers = self.docker.containers().list(py, Some(true), None, None, None);
+        if let Ok(list) = raw_containers.extract::<&pyo3::types::PyList>(py) {
+            for item in list.iter() {
+                let Ok(item_dict) = item.extract::<&PyDict>() else { continue };
+                let Some(labels) = item_dict.get_item("Labels") else { continue };
+                let Ok(labels_dict) = labels.extract::<&PyDict>() else { continue };
+
+                let Some(project) = labels_dict.get_item(PROJECT_LABEL) else { continue };
+                if project.extract::<String>().ok().as_deref() != Some(self.name.as_str()) {
+                    continue;
+                }
+
+                let Some(service_name) = labels_dict
+                    .get_item(SERVICE_LABEL)
+                    .and_then(|v| v.extract::<String>().ok())
+                else {
+                    continue;
+                };
+                let Some(id) = item_dict.get_item("Id").and_then(|v| v.extract::<String>().ok()) else {
+                    continue;
+                };
+
+                containers.entry(service_name).or_default().push(id);
+            }
+        }
+
+        let prefix = format!("{}_", self.name);
+        let mut networks: HashMap<String, String> = HashMap::new();
+        let all_networks = get_runtime().block_on(self.docker.networks().0.list(&Default::default()));
+        if let Ok(all_networks) = all_networks {
+            for network in all_networks {
+                let Some(network_name) = network.name else { continue };
+                let Some(logical) = network_name.strip_prefix(&prefix) else { continue };
+                if let Some(id) = network.id {
+                    networks.insert(logical.to_string(), id);
+                }
+            }
+        }
+
+        let mut volumes: HashMap<String, String> = HashMap::new();
+        let all_volumes = get_runtime().block_on(self.docker.volumes().0.list(&Default::default()));
+        if let Ok(response) = all_volumes {
+            for volume in response.volumes.into_iter().flatten() {
+                if let Some(logical) = volume.name.strip_prefix(&prefix) {
+                    volumes.insert(logical.to_string(), volume.name.clone());
+                }
+            }
+        }
+
+        self.state.status = if containers.is_empty() {
+            StackStatus::NotDeployed
+        } else if self.registered_services.iter().all(|(name, service)| {
+            containers.get(name).map(|ids| ids.len() as u32) == Some(service.get_replicas().max(1))
+        }) {
+            StackStatus::Running
+        } else {
+            StackStatus::PartiallyRunning
+        };
+
+        self.state.containers = containers;
+        self.state.networks = networks;
+        self.state.volumes = volumes;
+
+        Ok(())
+    }
+}
+
+/// A polling log-follow iterator returned by `Stack.logs(follow=True)`,
+/// mirroring `docker-compose logs -f`: each `next()` call blocks (GIL
+/// released between polls) until a tracked container has produced a new
+/// line, then returns it prefixed with its `service_replica` tag (colored
+/// per service when the process's standard output is a terminal).
+/// `close()` ends the iteration early.
+#[pyclass(name = "StackLogs")]
+pub struct Pyo3StackLogs {
+    docker: Docker,
+    targets: Vec<(String, String, usize)>,
+    timestamps: bool,
+    tail: Option<usize>,
+    since: Option<DateTime<Utc>>,
+    use_color: bool,
+    seen_lines: HashMap<String, usize>,
+    pending: VecDeque<String>,
+    stopped: bool,
+}
+
+#[pymethods]
+impl Pyo3StackLogs {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<String>> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                return Ok(Some(line));
+            }
+
+            if self.stopped {
+                return Ok(None);
+            }
+
+            let new_lines = py.allow_threads(|| self.poll_once());
+            if new_lines.is_empty() {
+                std::thread::sleep(Duration::from_millis(500));
+            } else {
+                self.pending.extend(new_lines);
+            }
+        }
+    }
+
+    /// Stop following; a subsequent `next()` simply ends the iteration.
+    fn close(&mut self) {
+        self.stopped = true;
+    }
+}
+
+impl Pyo3StackLogs {
+    /// Fetch each target's current logs, compare against how many lines
+    /// were already emitted for it, and return the newly-appeared ones
+    /// (tagged and colored), advancing the per-container line cursor.
+    fn poll_once(&mut self) -> Vec<String> {
+        let mut emitted = Vec::new();
+        let targets = self.targets.clone();
+
+        for (tag, container_id, color_index) in &targets {
+            let raw = Pyo3Stack::fetch_container_logs(
+                &self.docker,
+                container_id,
+                self.timestamps,
+                None,
+                self.since.is_none(),
+                self.since,
+            );
+            let all_lines: Vec<&str> = raw.lines().collect();
+
+            let tail = self.tail;
+            let seen = *self
+                .seen_lines
+                .entry(container_id.clone())
+                .or_insert_with(|| all_lines.len().saturating_sub(tail.unwrap_or(0)));
+
+            if all_lines.len() > seen {
+                for line in &all_lines[seen..] {
+                    emitted.push(Pyo3Stack::tag_line(line, tag, *color_index, self.use_color));
+                }
+                self.seen_lines.insert(container_id.clone(), all_lines.len());
+            }
+        }
+
+        emitted
+    }
+}
+
+impl Pyo3Stack {
+    /// Fetch a single container's captured log output, mirroring
+    /// `container.rs`'s own `__container_logs` helper but parameterized
+    /// over `since`/`tail`/`all` so `Stack.logs()` can reuse it across
+    /// every tracked container (and, via [`Pyo3StackLogs`], poll it
+    /// repeatedly for `follow` mode).
+    fn fetch_container_logs(
+        docker: &Docker,
+        container_id: &str,
+        timestamps: bool,
+        n_lines: Option<usize>,
+        all: bool,
+        since: Option<DateTime<Utc>>,
+    ) -> String {
+        let container = Container::new(docker.clone(), container_id);
+        let mut opts = LogsOpts::builder().stdout(true).stderr(true).timestamps(timestamps);
+        if let Some(n_lines) = n_lines {
+            opts = opts.n_lines(n_lines);
+        } else if all {
+            opts = opts.all();
+        }
+        if let Some(since) = since {
+            opts = opts.since(&since);
+        }
+        let opts = opts.build();
+
+        get_runtime().block_on(async {
+            let log = container
+                .logs(&opts)
+                .map(|chunk| match chunk {
+                    Ok(chunk) => chunk.to_vec(),
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        vec![]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+            String::from_utf8_lossy(&log).to_string()
+        })
+    }
+
+    /// Parse a compose-style memory limit (e.g. `"512m"`, `"1g"`, `"100kb"`,
+    /// or a plain byte count) into a byte count.
+    fn parse_memory_bytes(value: &str) -> Option<u64> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        let lower = value.to_ascii_lowercase();
+        let (digits, multiplier) = if let Some(stripped) = lower.strip_suffix("kb").or_else(|| lower.strip_suffix('k')) {
+            (stripped, 1024u64)
+        } else if let Some(stripped) = lower.strip_suffix("mb").or_else(|| lower.strip_suffix('m')) {
+            (stripped, 1024 * 1024)
+        } else if let Some(stripped) = lower.strip_suffix("gb").or_else(|| lower.strip_suffix('g')) {
+            (stripped, 1024 * 1024 * 1024)
+        } else if let Some(stripped) = lower.strip_suffix('b') {
+            (stripped, 1)
+        } else {
+            (lower.as_str(), 1)
+        };
+
+        digits.trim().parse::<u64>().ok().map(|n| n * multiplier)
+    }
+
+    /// Compose keys that `to_config_map` can populate but that have no
+    /// matching argument on `Pyo3Containers.create` - mirrors the spirit of
+    /// compose-go's DOCKER_START_KEYS split between create-time and
+    /// start-time config, except every key here is simply not modeled yet.
+    const UNSUPPORTED_CREATE_KEYS: &'static [&'static str] = &["hostname"];
+
+    /// Fail fast, with a clear error, on any compose key this crate can't
+    /// actually apply - rather than silently dropping it on the floor when
+    /// building the container, as `deploy_service`/`create_service_container`
+    /// used to.
+    fn check_unsupported_keys(config: &HashMap<String, String>) -> PyResult<()> {
+        for key in Self::UNSUPPORTED_CREATE_KEYS {
+            if config.contains_key(*key) {
+                return Err(crate::docker_error!(
+                    Configuration,
+                    format!("compose key '{key}' is not supported by this crate's container creation")
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute a stable hash of a service's resolved config map, used to
+    /// detect whether a deployed container is still up to date with its
+    /// `Service` definition. Keys are sorted first so the hash doesn't
+    /// depend on `HashMap` iteration order.
+    fn config_hash(config: &HashMap<String, String>) -> String {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut entries: Vec<(&String, &String)> = config.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut hasher = DefaultHasher::new();
+        for (key, value) in entries {
+            key.hash(&mut hasher);
+            value.hash(&mut hasher);
+        }
+
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Read back the `docker_pyo3.config_hash` label of an existing
+    /// container, if it has one and can still be inspected.
+    fn container_config_hash(&self, container_id: &str) -> Option<String> {
+        let container = self.docker.containers().get(container_id);
+        let info = container.inspect().ok()?;
+
+        Python::with_gil(|py| {
+            let info_dict = info.extract::<&PyDict>(py).ok()?;
+            let config = info_dict.get_item("Config")?;
+            let config_dict = config.extract::<&PyDict>().ok()?;
+            let labels = config_dict.get_item("Labels")?;
+            let labels_dict = labels.extract::<&PyDict>().ok()?;
+            let hash_value = labels_dict.get_item(CONFIG_HASH_LABEL)?;
+            hash_value.extract::<String>().ok()
+        })
+    }
+
+    /// Poll a container's `State` until it satisfies a compose-style
+    /// `depends_on` condition (`service_started`, `service_healthy`, or
+    /// `service_completed_successfully`), or `timeout` elapses.
+    fn container_satisfies_condition(&self, container_id: &str, condition: &str, timeout: Duration) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            let container = self.docker.containers().get(container_id);
+            if let Ok(info) = container.inspect() {
+                let satisfied = Python::with_gil(|py| -> Option<bool> {
+                    let info_dict = info.extract::<&PyDict>(py).ok()?;
+                    let state = info_dict.get_item("State")?;
+                    let state_dict = state.extract::<&PyDict>().ok()?;
+                    match condition {
+                        "service_healthy" => match state_dict.get_item("Health") {
+                            Some(health) => {
+                                let health_dict = health.extract::<&PyDict>().ok()?;
+                                let status: String = health_dict.get_item("Status")?.extract().ok()?;
+                                Some(status == "healthy")
+                            }
+                            // No healthcheck defined: running counts as healthy
+                            None => Some(state_dict.get_item("Running")?.extract().unwrap_or(false)),
+                        },
+                        "service_completed_successfully" => {
+                            let running: bool = state_dict.get_item("Running")?.extract().unwrap_or(false);
+                            let exit_code: i64 = state_dict.get_item("ExitCode")?.extract().unwrap_or(-1);
+                            Some(!running && exit_code == 0)
+                        }
+                        // "service_started" (and anything unrecognized) is
+                        // satisfied as soon as the container is running
+                        _ => {
+                            let running: bool = state_dict.get_item("Running")?.extract().unwrap_or(false);
+                            Some(running)
+                        }
+                    }
+                })
+                .unwrap_or(false);
+
+                if satisfied {
+                    return true;
+                }
+            }
+
+            if std::time::Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    /// Block until every condition-gated dependency of `service_name` is
+    /// satisfied, failing with a clear error naming the first dependency
+    /// that never became ready within `timeout`. Dependencies with no
+    /// explicit condition (or that aren't deployed yet) are left alone -
+    /// the wave ordering from [`Self::deploy_waves`] already guarantees
+    /// they exist before this is called.
+    fn wait_for_depends_on(&self, service_name: &str, timeout: Duration) -> PyResult<()> {
+        let service = &self.registered_services[service_name];
+        for dep_name in service.get_depends_on_conditions().keys() {
+            let Some(container_ids) = self.state.containers.get(dep_name) else {
+                continue;
+            };
+            let condition = service.get_start_condition(dep_name).unwrap_or("service_started");
+
+            let satisfied = container_ids
+                .iter()
+                .all(|id| self.container_satisfies_condition(id, condition, timeout));
+
+            if !satisfied {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "service '{}' timed out after {:?} waiting for dependency '{}' to satisfy condition '{}'",
+                    service_name, timeout, dep_name, condition
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Join `container_id` to `network_id`, registering `service_name` as a
+    /// network alias so peers on that network can resolve it by service
+    /// name - the DNS-by-service-name behavior the Stack abstraction
+    /// promises. Compose's per-network `aliases:` overrides aren't modeled
+    /// on `Service` yet, so the service name is always the only alias
+    /// registered. Best-effort: a failed connect is swallowed, matching
+    /// the rest of this file's container-to-network wiring.
+    fn connect_with_alias(&self, network_id: &str, container_id: &str, service_name: &str) {
+        let _ = Python::with_gil(|py| {
+            let aliases = pyo3::types::PyList::new(py, [service_name]);
+            self.docker.networks().get(network_id).connect(
+                py, container_id, None, Some(&aliases), None, None, None, None, None, None, None,
+                None, None, None,
+            )
+        });
+    }
+
+    /// Rewrite a service's `source:target[:mode]` volume string so that a
+    /// source naming a top-level declared volume resolves to the actual
+    /// (stack-namespaced) Docker volume created for it, instead of being
+    /// passed through as a literal host path.
+    fn resolve_volume_string(&self, volume: &str) -> String {
+        let Some((source, rest)) = volume.split_once(':') else {
+            return volume.to_string();
+        };
+
+        match self.state.volumes.get(source) {
+            Some(qualified) => format!("{}:{}", qualified, rest),
+            None => volume.to_string(),
+        }
+    }
+
+    /// Expand `${VAR}`-style references in `value` when `interpolate` is
+    /// set, otherwise return it unchanged.
+    fn maybe_interpolate(value: &str, interpolate: bool, vars: &HashMap<String, String>) -> PyResult<String> {
+        if interpolate {
+            Ok(interpolation::interpolate(value, vars)?)
+        } else {
+            Ok(value.to_string())
+        }
+    }
+
+    /// Compute a deployment order for the registered services via a
+    /// topological sort (Kahn's algorithm) over `depends_on` edges, so that
+    /// a service's dependencies always come before it. This is the
+    /// flattened form of [`Self::deploy_waves`]; see there for details.
+    fn deploy_order(&self) -> PyResult<Vec<String>> {
+        Ok(self.deploy_waves()?.into_iter().flatten().collect())
+    }
+
+    /// Group the registered services into deployment "waves" via a
+    /// topological sort (Kahn's algorithm) over `depends_on` edges: every
+    /// service in a wave has all of its dependencies satisfied by services
+    /// in earlier waves, so the services within a wave are independent of
+    /// each other and safe to deploy concurrently. Ties within a wave are
+    /// broken by service name for determinism. Errors if a `depends_on`
+    /// target isn't registered, or if the dependency graph has a cycle.
+    fn deploy_waves(&self) -> PyResult<Vec<Vec<String>>> {
+        for (service_name, service) in &self.registered_services {
+            for dep in service.get_depends_on() {
+                if !self.registered_services.contains_key(dep) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Service '{}' depends on '{}', which is not registered in stack '{}'",
+                        service_name, dep, self.name
+                    )));
+                }
+            }
+        }
+
+        let mut in_degree: HashMap<&str, usize> =
+            self.registered_services.keys().map(|n| (n.as_str(), 0)).collect();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (service_name, service) in &self.registered_services {
+            for dep in service.get_depends_on() {
+                *in_degree.get_mut(service_name.as_str()).unwrap() += 1;
+                dependents.entry(dep.as_str()).or_default().push(service_name.as_str());
+            }
+        }
+
+        let mut ready: Vec<&str> = in_degree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&name, _)| name)
+            .collect();
+        ready.sort_unstable();
+
+        let mut waves = Vec::new();
+        let mut visited = 0usize;
+        while !ready.is_empty() {
+            visited += ready.len();
+
+            let mut next_ready = Vec::new();
+            for &name in &ready {
+                if let Some(deps) = dependents.get(name) {
+                    for &dependent in deps {
+                        let degree = in_degree.get_mut(dependent).unwrap();
+                        *degree -= 1;
+                        if *degree == 0 {
+                            next_ready.push(dependent);
+                        }
+                    }
+                }
+            }
+            next_ready.sort_unstable();
+
+            waves.push(ready.into_iter().map(String::from).collect());
+            ready = next_ready;
+        }
+
+        if visited != self.registered_services.len() {
+            let mut cyclic: Vec<&str> = in_degree
+                .iter()
+                .filter(|(_, &degree)| degree > 0)
+                .map(|(&name, _)| name)
+                .collect();
+            cyclic.sort_unstable();
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Circular dependency detected among services: {}",
+                cyclic.join(", ")
+            )));
+        }
+
+        Ok(waves)
+    }
+
+    /// Build the variable table used for `${VAR}` interpolation: a sibling
+    /// `.env` file (lowest precedence), overridden by the process
+    /// environment, overridden by the caller-supplied `env` dict (highest).
+    fn interpolation_vars(dot_env_dir: Option<&std::path::Path>, env: Option<HashMap<String, String>>) -> HashMap<String, String> {
+        let mut vars = HashMap::new();
+
+        if let Some(dir) = dot_env_dir {
+            vars.extend(Self::read_dot_env_file(&dir.join(".env")));
+        }
+
+        vars.extend(std::env::vars());
+
+        if let Some(env) = env {
+            vars.extend(env);
+        }
+
+        vars
+    }
+
+    fn read_dot_env_file(path: &std::path::Path) -> HashMap<String, String> {
+        let mut vars = HashMap::new();
+
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            for line in contents.lines() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                if let Some((key, value)) = line.split_once('=') {
+                    vars.insert(key.trim().to_string(), value.trim().to_string());
+                }
+            }
+        }
+
+        vars
+    }
+
+    /// Parse already-interpolated compose YAML and register its services.
+    fn build_from_interpolated_yaml(docker: Pyo3Docker, yaml_content: String) -> PyResult<Pyo3Stack> {
         let compose: docker_compose_types::Compose = serde_yaml::from_str(&yaml_content)
             .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(
                 format!("Failed to parse docker-compose YAML: {}", e)
             ))?;
-        
+
+        Self::build_from_compose(docker, compose)
+    }
+
+    /// Build a `Stack` from an already-parsed compose document - shared by
+    /// `build_from_interpolated_yaml` (single document) and `from_files`
+    /// (one document merged from several).
+    fn build_from_compose(docker: Pyo3Docker, compose: docker_compose_types::Compose) -> PyResult<Pyo3Stack> {
         // Extract stack name from the compose data or use default
         let stack_name = "imported-stack".to_string(); // TODO: Better naming strategy
         let mut stack = Pyo3Stack::new(docker, stack_name);
-        
+
         // Import services
         for (service_name, service_config) in compose.services.0 {
             if let Some(service_config) = service_config {
@@ -875,14 +2724,20 @@ services:
                 stack.register_service(imported_service)?;
             }
         }
-        
-        // TODO: Import networks and volumes
-        
+
+        // Record top-level volumes/networks declarations; the actual
+        // Docker resources are created lazily in `up()`, same as the
+        // stack's default network.
+        if let Some(volumes) = compose.volumes {
+            stack.declared_volumes = volumes.into_keys().collect();
+        }
+        if let Some(networks) = compose.networks {
+            stack.declared_networks = networks.into_keys().collect();
+        }
+
         Ok(stack)
     }
-}
 
-impl Pyo3Stack {
     /// Convert a docker-compose service to a docker-pyo3 Service
     fn import_service(name: String, config: docker_compose_types::Service) -> PyResult<Service> {
         let mut service = Service::new(name);
@@ -1012,7 +2867,20 @@ impl Pyo3Stack {
         for volume in volume_strings {
             service.volume(volume);
         }
-        
+
+        // Handle networks - attaches this service to top-level declared
+        // networks at deploy time, in addition to the stack's default one
+        if let Some(networks) = config.networks {
+            match networks {
+                docker_compose_types::Networks::Simple(names) => {
+                    service.networks(names);
+                }
+                docker_compose_types::Networks::Advanced(names) => {
+                    service.networks(names.into_keys().collect());
+                }
+            }
+        }
+
         // Handle working directory
         if let Some(working_dir) = config.working_dir {
             service.working_dir(working_dir);
@@ -1022,7 +2890,12 @@ impl Pyo3Stack {
         if let Some(hostname) = config.hostname {
             service.hostname(hostname);
         }
-        
+
+        // Handle user
+        if let Some(user) = config.user {
+            service.user(user);
+        }
+
         // Handle restart policy
         if let Some(restart) = config.restart {
             service.restart_policy(restart);
@@ -1036,9 +2909,8 @@ impl Pyo3Stack {
                 }
             }
             docker_compose_types::DependsOnOptions::Conditional(deps) => {
-                // For conditional dependencies, just use the service names
-                for (service_name, _condition) in deps {
-                    service.depends_on_service(service_name);
+                for (service_name, condition) in deps {
+                    service.depends_on_service_with_condition(service_name, condition.condition);
                 }
             }
         }
@@ -1080,4 +2952,13 @@ impl Pyo3Stack {
         
         Ok(service)
     }
+}
+
+#[pymodule]
+pub fn stack(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Service>()?;
+    m.add_class::<Pyo3Project>()?;
+    m.add_class::<Pyo3Stack>()?;
+    m.add_class::<Pyo3StackLogs>()?;
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::DockerPyo3Error;
+use crate::stack::service_simple::Service;
+
+/// A named collection of [`Service`]s plus the top-level `networks:`,
+/// `volumes:`, and `secrets:` a compose file would declare alongside them.
+///
+/// Unlike `Pyo3Stack` (which owns a live `Docker` connection and actually
+/// creates containers/networks/volumes), `Project` is a pure, connection-
+/// free planning layer: it only knows how to resolve `depends_on` into a
+/// safe start order. Services are kept in registration order rather than a
+/// map, so that order can break ties deterministically in
+/// [`Self::start_order`].
+#[derive(Debug, Clone, Default)]
+pub struct Project {
+    name: String,
+    services: Vec<Service>,
+    networks: Vec<String>,
+    volumes: Vec<String>,
+    secrets: Vec<String>,
+}
+
+impl Project {
+    /// Create a new, empty project.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            services: Vec::new(),
+            networks: Vec::new(),
+            volumes: Vec::new(),
+            secrets: Vec::new(),
+        }
+    }
+
+    /// Get the project name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Register a service, in the order it should be preferred when
+    /// multiple services become startable at once.
+    pub fn service(mut self, service: Service) -> Self {
+        self.services.push(service);
+        self
+    }
+
+    /// Declare a top-level network, independent of any service that joins it.
+    pub fn network<S: Into<String>>(mut self, network: S) -> Self {
+        self.networks.push(network.into());
+        self
+    }
+
+    /// Declare a top-level named volume.
+    pub fn volume<S: Into<String>>(mut self, volume: S) -> Self {
+        self.volumes.push(volume.into());
+        self
+    }
+
+    /// Declare a top-level secret.
+    pub fn secret<S: Into<String>>(mut self, secret: S) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    pub fn networks(&self) -> &[String] {
+        &self.networks
+    }
+
+    pub fn volumes(&self) -> &[String] {
+        &self.volumes
+    }
+
+    pub fn secrets(&self) -> &[String] {
+        &self.secrets
+    }
+
+    /// Compute the order services should be started in so that every
+    /// service's `depends_on` targets start before it, via a topological
+    /// sort (Kahn's algorithm) over the `depends_on` graph.
+    ///
+    /// Ties - multiple services that are simultaneously startable - are
+    /// broken by registration order (the order [`Self::service`] was
+    /// called in), so the result is deterministic without imposing an
+    /// alphabetical ordering on unrelated services. Fails if any
+    /// `depends_on` name doesn't resolve to a registered service, or if the
+    /// dependency graph has a cycle (naming every service still stuck in it).
+    pub fn start_order(&self) -> Result<Vec<String>, DockerPyo3Error> {
+        let index_of: HashMap<&str, usize> =
+            self.services.iter().enumerate().map(|(i, s)| (s.name(), i)).collect();
+
+        for service in &self.services {
+            for dep in service.get_depends_on() {
+                if !index_of.contains_key(dep.as_str()) {
+                    return Err(DockerPyo3Error::InvalidParameter(format!(
+                        "project '{}': service '{}' depends on '{}', which is not registered",
+                        self.name,
+                        service.name(),
+                        dep
+                    )));
+                }
+            }
+        }
+
+        let mut in_degree = vec![0usize; self.services.len()];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); self.services.len()];
+        for service in &self.services {
+            let service_idx = index_of[service.name()];
+            for dep in service.get_depends_on() {
+                in_degree[service_idx] += 1;
+                dependents[index_of[dep.as_str()]].push(service_idx);
+            }
+        }
+
+        // Registration order is the tie-break: scan indices 0..n in order
+        // each round rather than sorting, so ties resolve to whichever
+        // ready service was registered first.
+        let mut remaining: HashSet<usize> = (0..self.services.len()).collect();
+        let mut order = Vec::with_capacity(self.services.len());
+
+        loop {
+            let ready: Vec<usize> = (0..self.services.len())
+                .filter(|i| remaining.contains(i) && in_degree[*i] == 0)
+                .collect();
+
+            if ready.is_empty() {
+                break;
+            }
+
+            for idx in ready {
+                remaining.remove(&idx);
+                order.push(self.services[idx].name().to_string());
+                for &dependent in &dependents[idx] {
+                    in_degree[dependent] -= 1;
+                }
+            }
+        }
+
+        if !remaining.is_empty() {
+            let mut stuck: Vec<&str> = remaining.iter().map(|&i| self.services[i].name()).collect();
+            stuck.sort_unstable();
+            return Err(DockerPyo3Error::InvalidParameter(format!(
+                "project '{}': dependency cycle among service(s): {}",
+                self.name,
+                stuck.join(", ")
+            )));
+        }
+
+        Ok(order)
+    }
+
+    /// Resolve [`Self::start_order`] and return the service names in the
+    /// order they should be started. `Project` itself has no `Docker`
+    /// connection to actually create containers with - callers wire the
+    /// returned order into `Pyo3Stack::up()` (or their own executor) to
+    /// bring the services up.
+    pub fn start(&self) -> Result<Vec<String>, DockerPyo3Error> {
+        self.start_order()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_order_respects_dependencies() {
+        let project = Project::new("demo")
+            .service(Service::new("web").depends_on_service("api"))
+            .service(Service::new("api").depends_on_service("db"))
+            .service(Service::new("db"));
+
+        let order = project.start_order().unwrap();
+        assert_eq!(order, vec!["db".to_string(), "api".to_string(), "web".to_string()]);
+    }
+
+    #[test]
+    fn test_start_order_ties_break_by_registration_order() {
+        let project = Project::new("demo")
+            .service(Service::new("b"))
+            .service(Service::new("a"))
+            .service(Service::new("c"));
+
+        assert_eq!(project.start_order().unwrap(), vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_unresolved_dependency_errors() {
+        let project = Project::new("demo").service(Service::new("web").depends_on_service("missing"));
+        let err = project.start_order().unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[test]
+    fn test_cycle_detected() {
+        let project = Project::new("demo")
+            .service(Service::new("a").depends_on_service("b"))
+            .service(Service::new("b").depends_on_service("a"));
+
+        let err = project.start_order().unwrap_err();
+        assert!(err.to_string().contains("a"));
+        assert!(err.to_string().contains("b"));
+    }
+
+    #[test]
+    fn test_declarations_are_tracked() {
+        let project = Project::new("demo")
+            .network("frontend")
+            .volume("data")
+            .secret("db_password");
+
+        assert_eq!(project.networks(), &["frontend".to_string()]);
+        assert_eq!(project.volumes(), &["data".to_string()]);
+        assert_eq!(project.secrets(), &["db_password".to_string()]);
+    }
+}
@@ -1,4 +1,10 @@
 use std::collections::HashMap;
+use std::path::Path;
+
+use serde_yaml::{Mapping, Value};
+
+use crate::error::DockerPyo3Error;
+use crate::stack::interpolation::interpolate;
 
 /// Build configuration for docker-compose build support
 #[derive(Debug, Clone)]
@@ -26,12 +32,21 @@ impl BuildConfig {
     }
 }
 
+/// A `deploy.update_config`/`deploy.rollback_config`-shaped rolling-update policy
+#[derive(Debug, Clone, Default)]
+pub struct UpdateConfig {
+    pub parallelism: Option<u64>,
+    pub delay: Option<String>,
+    pub order: Option<String>,
+}
+
 /// Resource limits configuration
 #[derive(Debug, Clone)]
 pub struct ResourceLimits {
     pub memory: Option<String>,
     pub memory_reservation: Option<String>,
     pub cpus: Option<String>,
+    pub cpu_reservation: Option<String>,
     pub cpu_shares: Option<u64>,
     pub cpu_quota: Option<u64>,
     pub cpu_period: Option<u64>,
@@ -43,6 +58,7 @@ impl Default for ResourceLimits {
             memory: None,
             memory_reservation: None,
             cpus: None,
+            cpu_reservation: None,
             cpu_shares: None,
             cpu_quota: None,
             cpu_period: None,
@@ -113,6 +129,48 @@ impl VolumeConfig {
     }
 }
 
+/// A secret or config mount with compose's advanced `target`/`uid`/`gid`/`mode` ownership options
+#[derive(Debug, Clone)]
+pub struct FileMount {
+    pub source: String,
+    pub target: Option<String>,
+    pub uid: Option<String>,
+    pub gid: Option<String>,
+    pub mode: Option<u32>,
+}
+
+/// A compose-style `depends_on` condition gating when a dependent service is
+/// allowed to start, mirroring the three conditions the compose spec (and
+/// `Pyo3Stack::container_satisfies_condition`) understand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyCondition {
+    ServiceStarted,
+    ServiceHealthy,
+    ServiceCompletedSuccessfully,
+}
+
+impl DependencyCondition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyCondition::ServiceStarted => "service_started",
+            DependencyCondition::ServiceHealthy => "service_healthy",
+            DependencyCondition::ServiceCompletedSuccessfully => "service_completed_successfully",
+        }
+    }
+}
+
+impl std::fmt::Display for DependencyCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<DependencyCondition> for String {
+    fn from(condition: DependencyCondition) -> Self {
+        condition.as_str().to_string()
+    }
+}
+
 /// Simplified independent Service class for composable service definitions
 /// This avoids the complex docker-compose-types API issues for now
 #[derive(Debug, Clone)]
@@ -130,13 +188,34 @@ pub struct Service {
     working_dir: Option<String>,
     networks: Vec<String>,
     depends_on: Vec<String>,
+    depends_on_conditions: HashMap<String, String>,
     restart_policy: Option<String>,
     hostname: Option<String>,
+    user: Option<String>,
     labels: HashMap<String, String>,
     replicas: u32,
+    mode: Option<String>,
+    placement_constraints: Vec<String>,
+    update_config: Option<UpdateConfig>,
+    rollback_config: Option<UpdateConfig>,
     resources: ResourceLimits,
     secrets: Vec<String>,
+    advanced_secrets: Vec<FileMount>,
+    configs: Vec<String>,
+    advanced_configs: Vec<FileMount>,
     healthcheck: Option<HashMap<String, String>>,
+    devices: Vec<String>,
+    stop_signal: Option<String>,
+    stop_grace_period: Option<String>,
+    privileged: bool,
+    cap_add: Vec<String>,
+    cap_drop: Vec<String>,
+    shm_size: Option<String>,
+    extra_hosts: Vec<String>,
+    cgroupns_mode: Option<String>,
+    userns_mode: Option<String>,
+    ulimits: Vec<String>,
+    sysctls: HashMap<String, String>,
 }
 
 impl Service {
@@ -156,13 +235,34 @@ impl Service {
             working_dir: None,
             networks: Vec::new(),
             depends_on: Vec::new(),
+            depends_on_conditions: HashMap::new(),
             restart_policy: None,
             hostname: None,
+            user: None,
             labels: HashMap::new(),
             replicas: 1,
+            mode: None,
+            placement_constraints: Vec::new(),
+            update_config: None,
+            rollback_config: None,
             resources: ResourceLimits::default(),
             secrets: Vec::new(),
+            advanced_secrets: Vec::new(),
+            configs: Vec::new(),
+            advanced_configs: Vec::new(),
             healthcheck: None,
+            devices: Vec::new(),
+            stop_signal: None,
+            stop_grace_period: None,
+            privileged: false,
+            cap_add: Vec::new(),
+            cap_drop: Vec::new(),
+            shm_size: None,
+            extra_hosts: Vec::new(),
+            cgroupns_mode: None,
+            userns_mode: None,
+            ulimits: Vec::new(),
+            sysctls: HashMap::new(),
         }
     }
 
@@ -178,6 +278,41 @@ impl Service {
         self
     }
 
+    /// Set the Docker image from a structured [`ImageRef`], re-rendering it
+    /// to a canonical string, so callers can build the reference
+    /// programmatically (e.g. pinning to a digest or rewriting the
+    /// registry) instead of formatting a string by hand.
+    pub fn with_image_ref(mut self, image_ref: crate::stack::image_ref::ImageRef) -> Self {
+        self.image = Some(image_ref.to_string());
+        self.build = None;
+        self
+    }
+
+    /// Retag the current image, clearing any digest pin. No-op if no image
+    /// is set yet or the current image can't be parsed as an `ImageRef`.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> Self {
+        if let Some(parsed) = self.image_ref() {
+            self.image = Some(parsed.with_tag(tag).to_string());
+        }
+        self
+    }
+
+    /// Pin the current image to a digest (e.g. `sha256:...`). No-op if no
+    /// image is set yet or the current image can't be parsed as an
+    /// `ImageRef`.
+    pub fn with_digest<S: Into<String>>(mut self, digest: S) -> Self {
+        if let Some(parsed) = self.image_ref() {
+            self.image = Some(parsed.with_digest(digest).to_string());
+        }
+        self
+    }
+
+    /// Parse the currently-set image string into a structured `ImageRef`,
+    /// or `None` if no image is set or it fails to parse.
+    pub fn image_ref(&self) -> Option<crate::stack::image_ref::ImageRef> {
+        crate::stack::image_ref::ImageRef::parse(self.image.as_deref()?).ok()
+    }
+
     /// Add port mappings (e.g., ["80:80", "443:443"])
     pub fn ports(mut self, ports: Vec<String>) -> Self {
         self.ports = ports;
@@ -244,6 +379,27 @@ impl Service {
         self
     }
 
+    /// Add a dependency that must satisfy a compose-style condition
+    /// (`service_started`, `service_healthy`, or
+    /// `service_completed_successfully`) before this service starts.
+    pub fn depends_on_service_with_condition<S: Into<String>, C: Into<String>>(
+        mut self,
+        service: S,
+        condition: C,
+    ) -> Self {
+        let service = service.into();
+        self.depends_on_conditions.insert(service.clone(), condition.into());
+        self.depends_on.push(service);
+        self
+    }
+
+    /// Add a dependency that must be healthy (i.e. pass its `healthcheck`)
+    /// before this service starts. Shorthand for
+    /// `depends_on_service_with_condition(service, DependencyCondition::ServiceHealthy)`.
+    pub fn depends_on_healthy<S: Into<String>>(self, service: S) -> Self {
+        self.depends_on_service_with_condition(service, DependencyCondition::ServiceHealthy)
+    }
+
     /// Set restart policy
     pub fn restart_policy<S: Into<String>>(mut self, policy: S) -> Self {
         self.restart_policy = Some(policy.into());
@@ -256,6 +412,84 @@ impl Service {
         self
     }
 
+    /// Set the user (and optionally group) the container's process runs as, e.g. "1000:1000"
+    pub fn user<S: Into<String>>(mut self, user: S) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Add a host device to expose to the container (e.g. `/dev/ttyUSB0:/dev/ttyUSB0`)
+    pub fn device<S: Into<String>>(mut self, device: S) -> Self {
+        self.devices.push(device.into());
+        self
+    }
+
+    /// Set the signal used to stop the container (default `SIGTERM`)
+    pub fn stop_signal<S: Into<String>>(mut self, signal: S) -> Self {
+        self.stop_signal = Some(signal.into());
+        self
+    }
+
+    /// Set how long to wait for the stop signal to take effect before Docker kills the container
+    pub fn stop_grace_period<S: Into<String>>(mut self, period: S) -> Self {
+        self.stop_grace_period = Some(period.into());
+        self
+    }
+
+    /// Run the container with extended (host-level) privileges
+    pub fn privileged(mut self, privileged: bool) -> Self {
+        self.privileged = privileged;
+        self
+    }
+
+    /// Add a Linux capability to grant beyond the default set (e.g. `NET_ADMIN`)
+    pub fn cap_add<S: Into<String>>(mut self, capability: S) -> Self {
+        self.cap_add.push(capability.into());
+        self
+    }
+
+    /// Drop a Linux capability from the default set (e.g. `ALL`)
+    pub fn cap_drop<S: Into<String>>(mut self, capability: S) -> Self {
+        self.cap_drop.push(capability.into());
+        self
+    }
+
+    /// Set the size of `/dev/shm` (e.g. `64m`)
+    pub fn shm_size<S: Into<String>>(mut self, size: S) -> Self {
+        self.shm_size = Some(size.into());
+        self
+    }
+
+    /// Add a `host:ip` entry to the container's `/etc/hosts`
+    pub fn extra_host<S: Into<String>>(mut self, host: S) -> Self {
+        self.extra_hosts.push(host.into());
+        self
+    }
+
+    /// Set the cgroup namespace mode (`private` or `host`)
+    pub fn cgroupns_mode<S: Into<String>>(mut self, mode: S) -> Self {
+        self.cgroupns_mode = Some(mode.into());
+        self
+    }
+
+    /// Set the user namespace mode (e.g. `host`)
+    pub fn userns_mode<S: Into<String>>(mut self, mode: S) -> Self {
+        self.userns_mode = Some(mode.into());
+        self
+    }
+
+    /// Add a ulimit in `name=soft[:hard]` form (e.g. `nofile=1024:2048`)
+    pub fn ulimit<S: Into<String>>(mut self, ulimit: S) -> Self {
+        self.ulimits.push(ulimit.into());
+        self
+    }
+
+    /// Set a kernel parameter (sysctl) for the container (e.g. `net.core.somaxconn`)
+    pub fn sysctl<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.sysctls.insert(key.into(), value.into());
+        self
+    }
+
     /// Add label
     pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
         self.labels.insert(key.into(), value.into());
@@ -274,6 +508,30 @@ impl Service {
         self
     }
 
+    /// Set the service mode under `deploy.mode` (`"replicated"` or `"global"`)
+    pub fn mode<S: Into<String>>(mut self, mode: S) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    /// Add a placement constraint (e.g. `"node.role==manager"`) under `deploy.placement.constraints`
+    pub fn placement_constraint<S: Into<String>>(mut self, expr: S) -> Self {
+        self.placement_constraints.push(expr.into());
+        self
+    }
+
+    /// Set the rolling update policy under `deploy.update_config`
+    pub fn update_config(mut self, parallelism: Option<u64>, delay: Option<String>, order: Option<String>) -> Self {
+        self.update_config = Some(UpdateConfig { parallelism, delay, order });
+        self
+    }
+
+    /// Set the rollback policy under `deploy.rollback_config`
+    pub fn rollback_config(mut self, parallelism: Option<u64>, delay: Option<String>, order: Option<String>) -> Self {
+        self.rollback_config = Some(UpdateConfig { parallelism, delay, order });
+        self
+    }
+
     /// Set memory limit
     pub fn memory<S: Into<String>>(mut self, limit: S) -> Self {
         self.resources.memory = Some(limit.into());
@@ -347,7 +605,13 @@ impl Service {
         self.resources.cpus = Some(cpus.into());
         self
     }
-    
+
+    /// Set reserved CPUs under `deploy.resources.reservations.cpus`
+    pub fn cpu_reservation<S: Into<String>>(mut self, cpus: S) -> Self {
+        self.resources.cpu_reservation = Some(cpus.into());
+        self
+    }
+
     /// Set CPU shares
     pub fn cpu_shares(mut self, shares: u64) -> Self {
         self.resources.cpu_shares = Some(shares);
@@ -399,15 +663,84 @@ impl Service {
         self.env_files.push(file.into());
         self
     }
-    
+
+    /// Resolve the effective runtime environment for this service: read each
+    /// `env_file()` in order (relative to `base_dir`), layer the explicit
+    /// `.env()`/`.environment()` entries on top (explicit wins ties with any
+    /// file), then expand `${VAR}` references in every resulting value
+    /// against that merged map plus the process environment.
+    ///
+    /// Returns an error if an env file can't be read, or if a value
+    /// references a mandatory variable (`${VAR:?message}`) that isn't set.
+    pub fn resolve_env(&self, base_dir: &Path) -> Result<HashMap<String, String>, DockerPyo3Error> {
+        let mut merged = HashMap::new();
+        for file in &self.env_files {
+            let path = base_dir.join(file);
+            let contents = std::fs::read_to_string(&path)?;
+            merged.extend(parse_env_file(&contents));
+        }
+        merged.extend(self.environment.clone());
+
+        let mut subst_vars: HashMap<String, String> = std::env::vars().collect();
+        subst_vars.extend(merged.clone());
+
+        merged
+            .into_iter()
+            .map(|(key, value)| Ok((key, interpolate(&value, &subst_vars)?)))
+            .collect()
+    }
+
     /// Add secret
     pub fn secret<S: Into<String>>(mut self, secret: S) -> Self {
         self.secrets.push(secret.into());
         self
     }
-    
+
+    /// Attach a secret mounted at `target` (or `/run/secrets/<name>` if
+    /// omitted) with the given ownership/mode, compose's advanced secret form
+    pub fn secret_advanced<S: Into<String>>(
+        mut self,
+        name: S,
+        target: Option<String>,
+        uid: Option<String>,
+        gid: Option<String>,
+        mode: Option<u32>,
+    ) -> Self {
+        self.advanced_secrets.push(FileMount { source: name.into(), target, uid, gid, mode });
+        self
+    }
+
+    /// Add config
+    pub fn config<S: Into<String>>(mut self, config: S) -> Self {
+        self.configs.push(config.into());
+        self
+    }
+
+    /// Attach a config mounted at `target` with the given ownership/mode,
+    /// compose's advanced config form
+    pub fn config_advanced<S: Into<String>>(
+        mut self,
+        name: S,
+        target: Option<String>,
+        uid: Option<String>,
+        gid: Option<String>,
+        mode: Option<u32>,
+    ) -> Self {
+        self.advanced_configs.push(FileMount { source: name.into(), target, uid, gid, mode });
+        self
+    }
+
     /// Add health check
-    pub fn healthcheck(mut self, test: Vec<String>, interval: Option<String>, timeout: Option<String>, retries: Option<u32>, start_period: Option<String>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn healthcheck(
+        mut self,
+        test: Vec<String>,
+        interval: Option<String>,
+        timeout: Option<String>,
+        retries: Option<u32>,
+        start_period: Option<String>,
+        start_interval: Option<String>,
+    ) -> Self {
         let mut hc = HashMap::new();
         hc.insert("test".to_string(), test.join(" "));
         if let Some(i) = interval {
@@ -422,6 +755,19 @@ impl Service {
         if let Some(sp) = start_period {
             hc.insert("start_period".to_string(), sp);
         }
+        if let Some(si) = start_interval {
+            hc.insert("start_interval".to_string(), si);
+        }
+        self.healthcheck = Some(hc);
+        self
+    }
+
+    /// Explicitly disable an image's inherited healthcheck (the
+    /// `test: ["NONE"]` / `disable: true` form from the v2.3+/v3.4 schemas).
+    pub fn disable_healthcheck(mut self) -> Self {
+        let mut hc = HashMap::new();
+        hc.insert("test".to_string(), "NONE".to_string());
+        hc.insert("disable".to_string(), "true".to_string());
         self.healthcheck = Some(hc);
         self
     }
@@ -438,6 +784,72 @@ impl Service {
         self.command.clone()
     }
 
+    /// Get the names of the services this service depends on
+    pub fn get_depends_on(&self) -> &[String] {
+        &self.depends_on
+    }
+
+    /// Get the compose-style conditions (`service_started`,
+    /// `service_healthy`, `service_completed_successfully`) attached to any
+    /// of this service's dependencies, keyed by dependency name.
+    pub fn get_depends_on_conditions(&self) -> &HashMap<String, String> {
+        &self.depends_on_conditions
+    }
+
+    /// Get the compose-style condition gating a single `dependency`, or
+    /// `None` if no explicit condition was recorded for it (in which case
+    /// callers should treat it as `service_started`).
+    pub fn get_start_condition(&self, dependency: &str) -> Option<&str> {
+        self.depends_on_conditions.get(dependency).map(String::as_str)
+    }
+
+    /// Get the service's simple `published:target` port mappings
+    pub fn get_ports(&self) -> &[String] {
+        &self.ports
+    }
+
+    /// Get the service's volume mounts
+    pub fn get_volumes(&self) -> &[String] {
+        &self.volumes
+    }
+
+    /// Get the networks this service attaches to, beyond the stack's
+    /// default network
+    pub fn get_networks(&self) -> &[String] {
+        &self.networks
+    }
+
+    /// Get the service's labels
+    pub fn get_labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Get the number of replicas configured for this service
+    pub fn get_replicas(&self) -> u32 {
+        self.replicas
+    }
+
+    /// Get the service's resource limits
+    pub fn get_resources(&self) -> &ResourceLimits {
+        &self.resources
+    }
+
+    /// Get the restart policy configured for this service
+    pub fn get_restart_policy(&self) -> Option<&str> {
+        self.restart_policy.as_deref()
+    }
+
+    /// Get the stop signal configured for this service (compose's
+    /// `stop_signal`), e.g. `"SIGINT"`
+    pub fn get_stop_signal(&self) -> Option<&str> {
+        self.stop_signal.as_deref()
+    }
+
+    /// Get the user (and optionally group) the container's process runs as
+    pub fn get_user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
     /// Get service configuration as a simple map for inspection
     pub fn to_config_map(&self) -> HashMap<String, String> {
         let mut config = HashMap::new();
@@ -486,7 +898,23 @@ impl Service {
         if let Some(ref hostname) = self.hostname {
             config.insert("hostname".to_string(), hostname.clone());
         }
-        
+
+        if let Some(ref user) = self.user {
+            config.insert("user".to_string(), user.clone());
+        }
+
+        if !self.devices.is_empty() {
+            config.insert("devices".to_string(), self.devices.join(","));
+        }
+
+        if let Some(ref stop_signal) = self.stop_signal {
+            config.insert("stop_signal".to_string(), stop_signal.clone());
+        }
+
+        if let Some(ref stop_grace_period) = self.stop_grace_period {
+            config.insert("stop_grace_period".to_string(), stop_grace_period.clone());
+        }
+
         if !self.labels.is_empty() {
             let labels_str: Vec<String> = self.labels
                 .iter()
@@ -496,7 +924,14 @@ impl Service {
         }
         
         config.insert("replicas".to_string(), self.replicas.to_string());
-        
+
+        if let Some(ref mode) = self.mode {
+            config.insert("mode".to_string(), mode.clone());
+        }
+        if !self.placement_constraints.is_empty() {
+            config.insert("placement_constraints".to_string(), self.placement_constraints.join(","));
+        }
+
         // Resource limits
         if let Some(ref memory) = self.resources.memory {
             config.insert("memory".to_string(), memory.clone());
@@ -507,6 +942,9 @@ impl Service {
         if let Some(ref cpus) = self.resources.cpus {
             config.insert("cpus".to_string(), cpus.clone());
         }
+        if let Some(ref cpu_res) = self.resources.cpu_reservation {
+            config.insert("cpu_reservation".to_string(), cpu_res.clone());
+        }
         if let Some(cpu_shares) = self.resources.cpu_shares {
             config.insert("cpu_shares".to_string(), cpu_shares.to_string());
         }
@@ -538,7 +976,20 @@ impl Service {
         if !self.secrets.is_empty() {
             config.insert("secrets".to_string(), self.secrets.join(","));
         }
-        
+        if !self.advanced_secrets.is_empty() {
+            let names: Vec<&str> = self.advanced_secrets.iter().map(|s| s.source.as_str()).collect();
+            config.insert("advanced_secrets".to_string(), names.join(","));
+        }
+
+        // Configs
+        if !self.configs.is_empty() {
+            config.insert("configs".to_string(), self.configs.join(","));
+        }
+        if !self.advanced_configs.is_empty() {
+            let names: Vec<&str> = self.advanced_configs.iter().map(|c| c.source.as_str()).collect();
+            config.insert("advanced_configs".to_string(), names.join(","));
+        }
+
         // Advanced ports
         if !self.advanced_ports.is_empty() {
             let ports_str: Vec<String> = self.advanced_ports
@@ -565,13 +1016,375 @@ impl Service {
         
         // Health check
         if let Some(ref hc) = self.healthcheck {
-            if let Some(test) = hc.get("test") {
-                config.insert("healthcheck_test".to_string(), test.clone());
+            for key in ["test", "interval", "timeout", "retries", "start_period", "start_interval", "disable"] {
+                if let Some(value) = hc.get(key) {
+                    config.insert(format!("healthcheck_{}", key), value.clone());
+                }
             }
         }
-        
+
+        // Security and isolation
+        if self.privileged {
+            config.insert("privileged".to_string(), "true".to_string());
+        }
+        if !self.cap_add.is_empty() {
+            config.insert("cap_add".to_string(), self.cap_add.join(","));
+        }
+        if !self.cap_drop.is_empty() {
+            config.insert("cap_drop".to_string(), self.cap_drop.join(","));
+        }
+        if let Some(ref shm_size) = self.shm_size {
+            config.insert("shm_size".to_string(), shm_size.clone());
+        }
+        if !self.extra_hosts.is_empty() {
+            config.insert("extra_hosts".to_string(), self.extra_hosts.join(","));
+        }
+        if let Some(ref cgroupns_mode) = self.cgroupns_mode {
+            config.insert("cgroupns_mode".to_string(), cgroupns_mode.clone());
+        }
+        if let Some(ref userns_mode) = self.userns_mode {
+            config.insert("userns_mode".to_string(), userns_mode.clone());
+        }
+        if !self.ulimits.is_empty() {
+            config.insert("ulimits".to_string(), self.ulimits.join(","));
+        }
+        if !self.sysctls.is_empty() {
+            let sysctls_str: Vec<String> = self.sysctls
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            config.insert("sysctls".to_string(), sysctls_str.join(","));
+        }
+
         config
     }
+
+    /// Serialize this service into a `serde_yaml::Value` compose-spec
+    /// fragment - the value that would sit under a `services.<name>:` key
+    /// in a real `docker-compose.yml`. Unlike [`Self::to_config_map`],
+    /// nothing is flattened to a comma-joined string: `ports`/`volumes`
+    /// keep their long-form `advanced_*` siblings as proper mappings,
+    /// `build` keeps its full shape, and `deploy.resources`/`healthcheck`/
+    /// `secrets`/`env_file` all round-trip as structured YAML.
+    pub fn to_compose_value(&self) -> serde_yaml::Value {
+        use serde_yaml::{Mapping, Value};
+
+        let mut map = Mapping::new();
+
+        if let Some(image) = &self.image {
+            map.insert(Value::from("image"), Value::from(image.clone()));
+        }
+
+        if let Some(build) = &self.build {
+            let mut build_map = Mapping::new();
+            build_map.insert(Value::from("context"), Value::from(build.context.clone()));
+            if let Some(dockerfile) = &build.dockerfile {
+                build_map.insert(Value::from("dockerfile"), Value::from(dockerfile.clone()));
+            }
+            if !build.args.is_empty() {
+                let mut args_map = Mapping::new();
+                for (key, value) in &build.args {
+                    args_map.insert(Value::from(key.clone()), Value::from(value.clone()));
+                }
+                build_map.insert(Value::from("args"), Value::Mapping(args_map));
+            }
+            if let Some(target) = &build.target {
+                build_map.insert(Value::from("target"), Value::from(target.clone()));
+            }
+            if !build.cache_from.is_empty() {
+                build_map.insert(
+                    Value::from("cache_from"),
+                    Value::Sequence(build.cache_from.iter().map(|s| Value::from(s.clone())).collect()),
+                );
+            }
+            if let Some(network) = &build.network {
+                build_map.insert(Value::from("network"), Value::from(network.clone()));
+            }
+            if let Some(ssh) = &build.ssh {
+                build_map.insert(Value::from("ssh"), Value::from(ssh.clone()));
+            }
+            map.insert(Value::from("build"), Value::Mapping(build_map));
+        }
+
+        let mut port_entries: Vec<Value> = self.ports.iter().map(|p| Value::from(p.clone())).collect();
+        for port in &self.advanced_ports {
+            let mut port_map = Mapping::new();
+            port_map.insert(Value::from("target"), Value::from(port.target));
+            if let Some(published) = port.published {
+                port_map.insert(Value::from("published"), Value::from(published));
+            }
+            port_map.insert(Value::from("protocol"), Value::from(port.protocol.clone()));
+            if let Some(mode) = &port.mode {
+                port_map.insert(Value::from("mode"), Value::from(mode.clone()));
+            }
+            port_entries.push(Value::Mapping(port_map));
+        }
+        if !port_entries.is_empty() {
+            map.insert(Value::from("ports"), Value::Sequence(port_entries));
+        }
+
+        if !self.environment.is_empty() {
+            let mut env_map = Mapping::new();
+            for (key, value) in &self.environment {
+                env_map.insert(Value::from(key.clone()), Value::from(value.clone()));
+            }
+            map.insert(Value::from("environment"), Value::Mapping(env_map));
+        }
+
+        if !self.env_files.is_empty() {
+            map.insert(
+                Value::from("env_file"),
+                Value::Sequence(self.env_files.iter().map(|f| Value::from(f.clone())).collect()),
+            );
+        }
+
+        let mut volume_entries: Vec<Value> = self.volumes.iter().map(|v| Value::from(v.clone())).collect();
+        for volume in &self.advanced_volumes {
+            let mut volume_map = Mapping::new();
+            volume_map.insert(Value::from("type"), Value::from(volume.volume_type.clone()));
+            volume_map.insert(Value::from("source"), Value::from(volume.source.clone()));
+            volume_map.insert(Value::from("target"), Value::from(volume.target.clone()));
+            volume_map.insert(Value::from("read_only"), Value::from(volume.read_only));
+            if let Some(bind_options) = &volume.bind_options {
+                let mut bind_map = Mapping::new();
+                for (key, value) in bind_options {
+                    bind_map.insert(Value::from(key.clone()), Value::from(value.clone()));
+                }
+                volume_map.insert(Value::from("bind"), Value::Mapping(bind_map));
+            }
+            volume_entries.push(Value::Mapping(volume_map));
+        }
+        if !volume_entries.is_empty() {
+            map.insert(Value::from("volumes"), Value::Sequence(volume_entries));
+        }
+
+        if let Some(command) = &self.command {
+            map.insert(
+                Value::from("command"),
+                Value::Sequence(command.iter().map(|c| Value::from(c.clone())).collect()),
+            );
+        }
+
+        if let Some(working_dir) = &self.working_dir {
+            map.insert(Value::from("working_dir"), Value::from(working_dir.clone()));
+        }
+
+        if !self.networks.is_empty() {
+            map.insert(
+                Value::from("networks"),
+                Value::Sequence(self.networks.iter().map(|n| Value::from(n.clone())).collect()),
+            );
+        }
+
+        if !self.depends_on.is_empty() {
+            if self.depends_on_conditions.is_empty() {
+                map.insert(
+                    Value::from("depends_on"),
+                    Value::Sequence(self.depends_on.iter().map(|d| Value::from(d.clone())).collect()),
+                );
+            } else {
+                let mut depends_map = Mapping::new();
+                for dep in &self.depends_on {
+                    let mut condition_map = Mapping::new();
+                    let condition = self.depends_on_conditions.get(dep).cloned().unwrap_or_else(|| "service_started".to_string());
+                    condition_map.insert(Value::from("condition"), Value::from(condition));
+                    depends_map.insert(Value::from(dep.clone()), Value::Mapping(condition_map));
+                }
+                map.insert(Value::from("depends_on"), Value::Mapping(depends_map));
+            }
+        }
+
+        if let Some(restart) = &self.restart_policy {
+            map.insert(Value::from("restart"), Value::from(restart.clone()));
+        }
+
+        if let Some(hostname) = &self.hostname {
+            map.insert(Value::from("hostname"), Value::from(hostname.clone()));
+        }
+
+        if let Some(user) = &self.user {
+            map.insert(Value::from("user"), Value::from(user.clone()));
+        }
+
+        if !self.devices.is_empty() {
+            map.insert(
+                Value::from("devices"),
+                Value::Sequence(self.devices.iter().map(|d| Value::from(d.clone())).collect()),
+            );
+        }
+
+        if let Some(stop_signal) = &self.stop_signal {
+            map.insert(Value::from("stop_signal"), Value::from(stop_signal.clone()));
+        }
+
+        if let Some(stop_grace_period) = &self.stop_grace_period {
+            map.insert(Value::from("stop_grace_period"), Value::from(stop_grace_period.clone()));
+        }
+
+        if self.privileged {
+            map.insert(Value::from("privileged"), Value::from(true));
+        }
+
+        if !self.cap_add.is_empty() {
+            map.insert(
+                Value::from("cap_add"),
+                Value::Sequence(self.cap_add.iter().map(|c| Value::from(c.clone())).collect()),
+            );
+        }
+
+        if !self.cap_drop.is_empty() {
+            map.insert(
+                Value::from("cap_drop"),
+                Value::Sequence(self.cap_drop.iter().map(|c| Value::from(c.clone())).collect()),
+            );
+        }
+
+        if let Some(shm_size) = &self.shm_size {
+            map.insert(Value::from("shm_size"), Value::from(shm_size.clone()));
+        }
+
+        if !self.extra_hosts.is_empty() {
+            map.insert(
+                Value::from("extra_hosts"),
+                Value::Sequence(self.extra_hosts.iter().map(|h| Value::from(h.clone())).collect()),
+            );
+        }
+
+        if let Some(cgroupns_mode) = &self.cgroupns_mode {
+            map.insert(Value::from("cgroupns_mode"), Value::from(cgroupns_mode.clone()));
+        }
+
+        if let Some(userns_mode) = &self.userns_mode {
+            map.insert(Value::from("userns_mode"), Value::from(userns_mode.clone()));
+        }
+
+        if !self.ulimits.is_empty() {
+            map.insert(
+                Value::from("ulimits"),
+                Value::Sequence(self.ulimits.iter().map(|u| Value::from(u.clone())).collect()),
+            );
+        }
+
+        if !self.sysctls.is_empty() {
+            let mut sysctls_map = Mapping::new();
+            for (key, value) in &self.sysctls {
+                sysctls_map.insert(Value::from(key.clone()), Value::from(value.clone()));
+            }
+            map.insert(Value::from("sysctls"), Value::Mapping(sysctls_map));
+        }
+
+        if !self.labels.is_empty() {
+            let mut labels_map = Mapping::new();
+            for (key, value) in &self.labels {
+                labels_map.insert(Value::from(key.clone()), Value::from(value.clone()));
+            }
+            map.insert(Value::from("labels"), Value::Mapping(labels_map));
+        }
+
+        if !self.secrets.is_empty() || !self.advanced_secrets.is_empty() {
+            map.insert(
+                Value::from("secrets"),
+                Value::Sequence(file_mount_entries(&self.secrets, &self.advanced_secrets)),
+            );
+        }
+
+        if !self.configs.is_empty() || !self.advanced_configs.is_empty() {
+            map.insert(
+                Value::from("configs"),
+                Value::Sequence(file_mount_entries(&self.configs, &self.advanced_configs)),
+            );
+        }
+
+        if let Some(healthcheck) = &self.healthcheck {
+            let mut hc_map = Mapping::new();
+            if let Some(test) = healthcheck.get("test") {
+                hc_map.insert(
+                    Value::from("test"),
+                    Value::Sequence(test.split(' ').map(|part| Value::from(part.to_string())).collect()),
+                );
+            }
+            for key in ["interval", "timeout", "start_period", "start_interval"] {
+                if let Some(value) = healthcheck.get(key) {
+                    hc_map.insert(Value::from(key), Value::from(value.clone()));
+                }
+            }
+            if let Some(retries) = healthcheck.get("retries").and_then(|r| r.parse::<u32>().ok()) {
+                hc_map.insert(Value::from("retries"), Value::from(retries));
+            }
+            if let Some(disable) = healthcheck.get("disable").and_then(|d| d.parse::<bool>().ok()) {
+                hc_map.insert(Value::from("disable"), Value::from(disable));
+            }
+            map.insert(Value::from("healthcheck"), Value::Mapping(hc_map));
+        }
+
+        let memory_limit_set = self.resources.memory.is_some() || self.resources.cpus.is_some();
+        let memory_reservation_set =
+            self.resources.memory_reservation.is_some() || self.resources.cpu_reservation.is_some();
+        let deploy_set = memory_limit_set
+            || memory_reservation_set
+            || self.replicas != 1
+            || self.mode.is_some()
+            || !self.placement_constraints.is_empty()
+            || self.update_config.is_some()
+            || self.rollback_config.is_some();
+        if deploy_set {
+            let mut deploy_map = Mapping::new();
+            if self.replicas != 1 {
+                deploy_map.insert(Value::from("replicas"), Value::from(self.replicas));
+            }
+            if let Some(ref mode) = self.mode {
+                deploy_map.insert(Value::from("mode"), Value::from(mode.clone()));
+            }
+            if !self.placement_constraints.is_empty() {
+                let mut placement_map = Mapping::new();
+                placement_map.insert(
+                    Value::from("constraints"),
+                    Value::Sequence(self.placement_constraints.iter().map(|c| Value::from(c.clone())).collect()),
+                );
+                deploy_map.insert(Value::from("placement"), Value::Mapping(placement_map));
+            }
+            if let Some(ref update_config) = self.update_config {
+                deploy_map.insert(Value::from("update_config"), update_config_value(update_config));
+            }
+            if let Some(ref rollback_config) = self.rollback_config {
+                deploy_map.insert(Value::from("rollback_config"), update_config_value(rollback_config));
+            }
+
+            let mut resources_map = Mapping::new();
+            if memory_limit_set {
+                let mut limits_map = Mapping::new();
+                if let Some(memory) = &self.resources.memory {
+                    limits_map.insert(Value::from("memory"), Value::from(memory.clone()));
+                }
+                if let Some(cpus) = &self.resources.cpus {
+                    limits_map.insert(Value::from("cpus"), Value::from(cpus.clone()));
+                }
+                resources_map.insert(Value::from("limits"), Value::Mapping(limits_map));
+            }
+            if memory_reservation_set {
+                let mut reservations_map = Mapping::new();
+                if let Some(memory_reservation) = &self.resources.memory_reservation {
+                    reservations_map.insert(Value::from("memory"), Value::from(memory_reservation.clone()));
+                }
+                if let Some(cpu_reservation) = &self.resources.cpu_reservation {
+                    reservations_map.insert(Value::from("cpus"), Value::from(cpu_reservation.clone()));
+                }
+                resources_map.insert(Value::from("reservations"), Value::Mapping(reservations_map));
+            }
+            if !resources_map.is_empty() {
+                deploy_map.insert(Value::from("resources"), Value::Mapping(resources_map));
+            }
+
+            map.insert(Value::from("deploy"), Value::Mapping(deploy_map));
+        }
+
+        Value::Mapping(map)
+    }
+
+    /// Render [`Self::to_compose_value`] as a YAML document string.
+    pub fn to_compose_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(&self.to_compose_value())
+    }
 }
 
 // Convenience constructors for common service types
@@ -600,6 +1413,72 @@ impl Service {
     }
 }
 
+/// Render a service's simple (name-only) and advanced (`target`/`uid`/`gid`/`mode`)
+/// secret or config mounts as the mixed short/long sequence compose expects.
+fn file_mount_entries(simple: &[String], advanced: &[FileMount]) -> Vec<Value> {
+    let mut entries: Vec<Value> = simple.iter().map(|name| Value::from(name.clone())).collect();
+    for mount in advanced {
+        let mut mount_map = Mapping::new();
+        mount_map.insert(Value::from("source"), Value::from(mount.source.clone()));
+        if let Some(ref target) = mount.target {
+            mount_map.insert(Value::from("target"), Value::from(target.clone()));
+        }
+        if let Some(ref uid) = mount.uid {
+            mount_map.insert(Value::from("uid"), Value::from(uid.clone()));
+        }
+        if let Some(ref gid) = mount.gid {
+            mount_map.insert(Value::from("gid"), Value::from(gid.clone()));
+        }
+        if let Some(mode) = mount.mode {
+            mount_map.insert(Value::from("mode"), Value::from(mode));
+        }
+        entries.push(Value::Mapping(mount_map));
+    }
+    entries
+}
+
+/// Render an [`UpdateConfig`] as the `parallelism`/`delay`/`order` mapping
+/// `deploy.update_config`/`deploy.rollback_config` expect.
+fn update_config_value(config: &UpdateConfig) -> Value {
+    let mut map = Mapping::new();
+    if let Some(parallelism) = config.parallelism {
+        map.insert(Value::from("parallelism"), Value::from(parallelism));
+    }
+    if let Some(ref delay) = config.delay {
+        map.insert(Value::from("delay"), Value::from(delay.clone()));
+    }
+    if let Some(ref order) = config.order {
+        map.insert(Value::from("order"), Value::from(order.clone()));
+    }
+    Value::Mapping(map)
+}
+
+/// Parse a `.env`-style file's contents into a `KEY=value` map: blank lines
+/// and `#`-prefixed comments are skipped, and surrounding single or double
+/// quotes are stripped from values.
+fn parse_env_file(contents: &str) -> HashMap<String, String> {
+    let mut vars = HashMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            vars.insert(key.trim().to_string(), strip_quotes(value.trim()).to_string());
+        }
+    }
+    vars
+}
+
+fn strip_quotes(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -724,10 +1603,168 @@ mod tests {
                 Some("30s".to_string()),
                 Some("10s".to_string()),
                 Some(3),
-                Some("40s".to_string())
+                Some("40s".to_string()),
+                None
             );
 
         let config = service.to_config_map();
         assert!(config.get("healthcheck_test").unwrap().contains("curl -f http://localhost:8080/health"));
+        assert_eq!(config.get("healthcheck_interval"), Some(&"30s".to_string()));
+        assert_eq!(config.get("healthcheck_retries"), Some(&"3".to_string()));
+    }
+
+    #[test]
+    fn test_devices_and_stop_settings() {
+        let service = Service::new("app")
+            .device("/dev/ttyUSB0:/dev/ttyUSB0")
+            .stop_signal("SIGINT")
+            .stop_grace_period("30s");
+
+        let config = service.to_config_map();
+        assert_eq!(config.get("devices"), Some(&"/dev/ttyUSB0:/dev/ttyUSB0".to_string()));
+        assert_eq!(config.get("stop_signal"), Some(&"SIGINT".to_string()));
+        assert_eq!(config.get("stop_grace_period"), Some(&"30s".to_string()));
+    }
+
+    #[test]
+    fn test_security_and_isolation_settings() {
+        let service = Service::new("app")
+            .privileged(true)
+            .cap_add("NET_ADMIN")
+            .cap_drop("ALL")
+            .shm_size("64m")
+            .extra_host("db.local:10.0.0.5")
+            .cgroupns_mode("host")
+            .userns_mode("host")
+            .ulimit("nofile=1024:2048")
+            .sysctl("net.core.somaxconn", "1024");
+
+        let config = service.to_config_map();
+        assert_eq!(config.get("privileged"), Some(&"true".to_string()));
+        assert_eq!(config.get("cap_add"), Some(&"NET_ADMIN".to_string()));
+        assert_eq!(config.get("cap_drop"), Some(&"ALL".to_string()));
+        assert_eq!(config.get("shm_size"), Some(&"64m".to_string()));
+        assert_eq!(config.get("extra_hosts"), Some(&"db.local:10.0.0.5".to_string()));
+        assert_eq!(config.get("cgroupns_mode"), Some(&"host".to_string()));
+        assert_eq!(config.get("userns_mode"), Some(&"host".to_string()));
+        assert_eq!(config.get("ulimits"), Some(&"nofile=1024:2048".to_string()));
+        assert_eq!(config.get("sysctls"), Some(&"net.core.somaxconn=1024".to_string()));
+
+        let value = service.to_compose_value();
+        assert_eq!(value.get("privileged").unwrap().as_bool(), Some(true));
+        assert_eq!(value.get("shm_size").unwrap().as_str(), Some("64m"));
+        assert_eq!(value.get("cap_add").unwrap().as_sequence().unwrap().len(), 1);
+        assert_eq!(value.get("sysctls").unwrap().get("net.core.somaxconn").unwrap().as_str(), Some("1024"));
+    }
+
+    #[test]
+    fn test_resolve_env_layers_files_under_explicit_and_interpolates() {
+        let dir = std::env::temp_dir().join(format!("docker_pyo3_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(".env"),
+            "# a comment\n\nHOST=db.local\nPORT=5432\nQUOTED=\"hello\"\n",
+        )
+        .unwrap();
+
+        let service = Service::new("app")
+            .env_file(".env")
+            .env("PORT", "6543")
+            .env("URL", "postgres://${HOST}:${PORT}");
+
+        let resolved = service.resolve_env(&dir).unwrap();
+        assert_eq!(resolved.get("HOST"), Some(&"db.local".to_string()));
+        assert_eq!(resolved.get("PORT"), Some(&"6543".to_string()));
+        assert_eq!(resolved.get("QUOTED"), Some(&"hello".to_string()));
+        assert_eq!(resolved.get("URL"), Some(&"postgres://db.local:6543".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_env_mandatory_variable_errors() {
+        let dir = std::env::temp_dir();
+        let service = Service::new("app").env("URL", "${REQUIRED:?REQUIRED must be set}");
+        let err = service.resolve_env(&dir).unwrap_err();
+        assert!(matches!(err, DockerPyo3Error::Configuration(_)));
+    }
+
+    #[test]
+    fn test_depends_on_healthy_sets_service_healthy_condition() {
+        let service = Service::new("web").depends_on_healthy("db");
+        assert_eq!(service.get_depends_on(), &["db".to_string()]);
+        assert_eq!(service.get_start_condition("db"), Some(DependencyCondition::ServiceHealthy.as_str()));
+    }
+
+    #[test]
+    fn test_compose_value_preserves_structure() {
+        let mut service = Service::new("web")
+            .image("nginx:latest")
+            .depends_on_service_with_condition("db", "service_healthy");
+        service.advanced_ports.push(PortConfig { target: 8080, published: Some(80), protocol: "tcp".to_string(), mode: None });
+        service.advanced_volumes.push(VolumeConfig {
+            source: "data".to_string(),
+            target: "/data".to_string(),
+            volume_type: "volume".to_string(),
+            read_only: true,
+            bind_options: None,
+        });
+
+        let value = service.to_compose_value();
+
+        let ports = value.get("ports").unwrap().as_sequence().unwrap();
+        let port_entry = &ports[0];
+        assert_eq!(port_entry.get("target").unwrap().as_u64(), Some(8080));
+        assert_eq!(port_entry.get("published").unwrap().as_u64(), Some(80));
+
+        let volumes = value.get("volumes").unwrap().as_sequence().unwrap();
+        let volume_entry = &volumes[0];
+        assert_eq!(volume_entry.get("source").unwrap().as_str(), Some("data"));
+        assert_eq!(volume_entry.get("read_only").unwrap().as_bool(), Some(true));
+
+        let depends_on = value.get("depends_on").unwrap();
+        let db_condition = depends_on.get("db").unwrap();
+        assert_eq!(db_condition.get("condition").unwrap().as_str(), Some("service_healthy"));
+    }
+
+    #[test]
+    fn test_compose_value_build_block() {
+        let mut build = BuildConfig::new("./app");
+        build.dockerfile = Some("Dockerfile.prod".to_string());
+        build.target = Some("release".to_string());
+        build.args.insert("VERSION".to_string(), "1.0".to_string());
+
+        let mut service = Service::new("app");
+        service.build = Some(build);
+
+        let value = service.to_compose_value();
+        let build_entry = value.get("build").unwrap();
+        assert_eq!(build_entry.get("context").unwrap().as_str(), Some("./app"));
+        assert_eq!(build_entry.get("dockerfile").unwrap().as_str(), Some("Dockerfile.prod"));
+        assert_eq!(build_entry.get("target").unwrap().as_str(), Some("release"));
+        assert!(value.get("image").is_none());
+    }
+
+    #[test]
+    fn test_compose_value_deploy_resources() {
+        let mut service = Service::new("web").image("nginx:latest");
+        service.resources.memory = Some("512m".to_string());
+        service.resources.memory_reservation = Some("256m".to_string());
+        service.replicas = 3;
+
+        let value = service.to_compose_value();
+        let deploy = value.get("deploy").unwrap();
+        assert_eq!(deploy.get("replicas").unwrap().as_u64(), Some(3));
+        let resources = deploy.get("resources").unwrap();
+        assert_eq!(resources.get("limits").unwrap().get("memory").unwrap().as_str(), Some("512m"));
+        assert_eq!(resources.get("reservations").unwrap().get("memory").unwrap().as_str(), Some("256m"));
+    }
+
+    #[test]
+    fn test_compose_yaml_round_trips_as_valid_yaml() {
+        let service = Service::new("web").image("nginx:latest").ports(vec!["80:80".to_string()]);
+        let yaml = service.to_compose_yaml().unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.get("image").unwrap().as_str(), Some("nginx:latest"));
     }
 }
\ No newline at end of file
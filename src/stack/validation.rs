@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+
+use docker_compose_types::{Compose, Service as ComposeService};
+
+use crate::error::DockerPyo3Error;
+use crate::stack::service_simple::Service;
+
+/// The family of Compose schema a file is being validated against.
+///
+/// Mirrors the split upstream keeps between `config_schema_v2.x` (legacy,
+/// single-host `docker-compose`) and `config_schema_v3.x`/`compose_spec.json`
+/// (swarm-capable, `docker stack deploy`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeVersion {
+    V2,
+    V3,
+}
+
+impl ComposeVersion {
+    /// Parse a `version:` string such as `"2.4"` or `"3.8"`.
+    pub fn parse(version: &str) -> Result<Self, DockerPyo3Error> {
+        let major = version
+            .split('.')
+            .next()
+            .and_then(|s| s.parse::<u32>().ok())
+            .ok_or_else(|| {
+                DockerPyo3Error::InvalidParameter(format!("unrecognized compose version: '{version}'"))
+            })?;
+
+        match major {
+            2 => Ok(ComposeVersion::V2),
+            3 => Ok(ComposeVersion::V3),
+            _ => Err(DockerPyo3Error::InvalidParameter(format!(
+                "unsupported compose version: '{version}'"
+            ))),
+        }
+    }
+}
+
+/// Validate every service in `compose` against `version`, returning every
+/// violation found rather than stopping at the first one.
+pub fn validate_compose(compose: &Compose, version: ComposeVersion) -> Result<(), DockerPyo3Error> {
+    let mut errors = Vec::new();
+
+    if let Some(services) = &compose.services {
+        for (name, service) in &services.0 {
+            if let Some(service) = service {
+                if let Err(e) = validate_service(name, service, version) {
+                    errors.push(e.to_string());
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(DockerPyo3Error::InvalidParameter(errors.join("; ")))
+    }
+}
+
+/// Validate a single service's version-sensitive fields, reporting the
+/// offending field path in the error message.
+pub fn validate_service(
+    name: &str,
+    service: &ComposeService,
+    version: ComposeVersion,
+) -> Result<(), DockerPyo3Error> {
+    if version == ComposeVersion::V2 {
+        if let Some(deploy) = &service.deploy {
+            if deploy.replicas.is_some() || deploy.mode.is_some() || deploy.placement.is_some() {
+                return Err(DockerPyo3Error::InvalidParameter(format!(
+                    "services.{name}.deploy: swarm-only fields (replicas/mode/placement) are not valid in Compose v2 files"
+                )));
+            }
+        }
+        if service.secrets.is_some() || service.configs.is_some() {
+            return Err(DockerPyo3Error::InvalidParameter(format!(
+                "services.{name}: top-level secrets/configs require Compose v3+"
+            )));
+        }
+    }
+
+    if version == ComposeVersion::V3 {
+        if service.build.is_some() && service.image.is_none() && service.deploy.is_some() {
+            if let Some(deploy) = &service.deploy {
+                if deploy.mode.as_deref() == Some("global") && deploy.replicas.is_some() {
+                    return Err(DockerPyo3Error::InvalidParameter(format!(
+                        "services.{name}.deploy: 'replicas' is not valid when mode is 'global'"
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Semantic validation pass over an already-built stack: the checks a
+/// Compose schema alone can't make, because they need to cross-reference a
+/// service against its siblings and the top-level `networks:`/`volumes:`
+/// declarations. Collects every violation instead of stopping at the
+/// first, the way `docker compose config` reports problems all at once.
+pub fn validate_stack_semantics(
+    services: &HashMap<String, Service>,
+    declared_networks: &[String],
+    declared_volumes: &[String],
+) -> Result<(), Vec<DockerPyo3Error>> {
+    let mut errors = Vec::new();
+
+    for (name, service) in services {
+        let config = service.to_config_map();
+
+        for dep in service.get_depends_on() {
+            if !services.contains_key(dep) {
+                errors.push(DockerPyo3Error::InvalidParameter(format!(
+                    "services.{name}.depends_on: no such service '{dep}'"
+                )));
+            }
+        }
+
+        let has_image = config.contains_key("image");
+        let has_build = config.contains_key("build_context");
+        if has_image == has_build {
+            errors.push(DockerPyo3Error::InvalidParameter(format!(
+                "services.{name}: exactly one of 'image' or 'build' is required"
+            )));
+        }
+
+        for port in service.get_ports() {
+            if let Err(e) = validate_port_mapping(port) {
+                errors.push(DockerPyo3Error::InvalidParameter(format!(
+                    "services.{name}.ports: '{port}' {e}"
+                )));
+            }
+        }
+
+        for volume in service.get_volumes() {
+            match validate_volume_mapping(volume) {
+                Err(e) => errors.push(DockerPyo3Error::InvalidParameter(format!(
+                    "services.{name}.volumes: '{volume}' {e}"
+                ))),
+                Ok(Some(source)) if is_named_volume_reference(source) && !declared_volumes.iter().any(|v| v == source) => {
+                    errors.push(DockerPyo3Error::InvalidParameter(format!(
+                        "services.{name}.volumes: undeclared volume '{source}'"
+                    )));
+                }
+                Ok(_) => {}
+            }
+        }
+
+        for network in service.get_networks() {
+            if !declared_networks.iter().any(|n| n == network) {
+                errors.push(DockerPyo3Error::InvalidParameter(format!(
+                    "services.{name}.networks: undeclared network '{network}'"
+                )));
+            }
+        }
+
+        for key in ["healthcheck_interval", "healthcheck_timeout", "healthcheck_start_period"] {
+            if let Some(value) = config.get(key) {
+                if parse_compose_duration(value).is_none() {
+                    errors.push(DockerPyo3Error::InvalidParameter(format!(
+                        "services.{name}.{key}: '{value}' is not a valid duration"
+                    )));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate a short-syntax port mapping: `container[/proto]` or
+/// `host:container[/proto]`, where `host`/`container` are integers (or
+/// `host` a `lo-hi` range) and `proto` is `tcp` or `udp`.
+fn validate_port_mapping(port: &str) -> Result<(), String> {
+    let (port, proto) = match port.rsplit_once('/') {
+        Some((rest, proto)) => (rest, Some(proto)),
+        None => (port, None),
+    };
+
+    if let Some(proto) = proto {
+        if proto != "tcp" && proto != "udp" {
+            return Err(format!("unknown protocol '{proto}'"));
+        }
+    }
+
+    let parts: Vec<&str> = port.split(':').collect();
+    match parts.as_slice() {
+        [container] => validate_port_number_or_range(container),
+        [host, container] => validate_port_number_or_range(host).and_then(|()| validate_port_number_or_range(container)),
+        _ => Err("expected 'container' or 'host:container'".to_string()),
+    }
+}
+
+fn validate_port_number_or_range(part: &str) -> Result<(), String> {
+    match part.split_once('-') {
+        Some((lo, hi)) => {
+            lo.parse::<u16>().map_err(|_| format!("'{lo}' is not a valid port"))?;
+            hi.parse::<u16>().map_err(|_| format!("'{hi}' is not a valid port"))?;
+            Ok(())
+        }
+        None => part.parse::<u16>().map(|_| ()).map_err(|_| format!("'{part}' is not a valid port")),
+    }
+}
+
+/// Validate a short-syntax volume mapping: `src:dst[:ro|rw]`, or a bare
+/// `dst` for an anonymous volume. Returns the `src` segment when present,
+/// so the caller can check it against declared named volumes.
+fn validate_volume_mapping(volume: &str) -> Result<Option<&str>, String> {
+    let parts: Vec<&str> = volume.split(':').collect();
+    match parts.as_slice() {
+        [dst] if !dst.is_empty() => Ok(None),
+        [src, dst] if !src.is_empty() && !dst.is_empty() => Ok(Some(*src)),
+        [src, dst, mode] if !src.is_empty() && !dst.is_empty() => {
+            if *mode == "ro" || *mode == "rw" {
+                Ok(Some(*src))
+            } else {
+                Err(format!("unknown mount mode '{mode}'"))
+            }
+        }
+        _ => Err("expected 'dst', 'src:dst', or 'src:dst:ro|rw'".to_string()),
+    }
+}
+
+/// A volume `src` names a top-level declared volume (rather than a host
+/// path) when it isn't absolute, relative, or Windows-drive-rooted.
+fn is_named_volume_reference(source: &str) -> bool {
+    !source.starts_with('/') && !source.starts_with('.') && !source.contains('\\') && !source.contains(':')
+}
+
+/// Parse a Compose-style duration string (`"10s"`, `"1m30s"`, `"1h"`) made
+/// of `<number><unit>` segments where `unit` is `h`, `m`, `s`, `ms`, `us`,
+/// or `ns`. Returns `None` if any segment fails to parse.
+fn parse_compose_duration(value: &str) -> Option<std::time::Duration> {
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut total = std::time::Duration::ZERO;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(0);
+        if digits_end == 0 {
+            return None;
+        }
+        let (number, after_number) = rest.split_at(digits_end);
+        let amount: f64 = number.parse().ok()?;
+
+        let unit_end = after_number
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(after_number.len());
+        let (unit, remainder) = after_number.split_at(unit_end);
+
+        let seconds_per_unit = match unit {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            "us" | "\u{b5}s" => 0.000_001,
+            "ns" => 0.000_000_001,
+            _ => return None,
+        };
+
+        total += std::time::Duration::from_secs_f64(amount * seconds_per_unit);
+        rest = remainder;
+    }
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(ComposeVersion::parse("2.4").unwrap(), ComposeVersion::V2);
+        assert_eq!(ComposeVersion::parse("3.8").unwrap(), ComposeVersion::V3);
+        assert!(ComposeVersion::parse("bogus").is_err());
+        assert!(ComposeVersion::parse("4.0").is_err());
+    }
+
+    #[test]
+    fn test_deploy_rejected_on_v2() {
+        let mut service = ComposeService::default();
+        service.deploy = Some(docker_compose_types::Deploy {
+            replicas: Some(3),
+            ..Default::default()
+        });
+
+        let err = validate_service("web", &service, ComposeVersion::V2).unwrap_err();
+        assert!(err.to_string().contains("services.web.deploy"));
+    }
+
+    #[test]
+    fn test_plain_service_valid_on_v2() {
+        let service = ComposeService {
+            image: Some("nginx:latest".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_service("web", &service, ComposeVersion::V2).is_ok());
+    }
+
+    #[test]
+    fn test_port_mapping_grammar() {
+        assert!(validate_port_mapping("8080").is_ok());
+        assert!(validate_port_mapping("80:8080").is_ok());
+        assert!(validate_port_mapping("80:8080/udp").is_ok());
+        assert!(validate_port_mapping("8000-8010:8000-8010").is_ok());
+        assert!(validate_port_mapping("notaport").is_err());
+        assert!(validate_port_mapping("80:8080/sctp").is_err());
+    }
+
+    #[test]
+    fn test_volume_mapping_grammar() {
+        assert_eq!(validate_volume_mapping("data").unwrap(), None);
+        assert_eq!(validate_volume_mapping("mydata:/var/lib/data").unwrap(), Some("mydata"));
+        assert_eq!(validate_volume_mapping("./host:/container:ro").unwrap(), Some("./host"));
+        assert!(validate_volume_mapping("/a:/b:bogus").is_err());
+        assert!(validate_volume_mapping("").is_err());
+    }
+
+    #[test]
+    fn test_named_volume_reference() {
+        assert!(is_named_volume_reference("mydata"));
+        assert!(!is_named_volume_reference("/abs/path"));
+        assert!(!is_named_volume_reference("./rel/path"));
+    }
+
+    #[test]
+    fn test_parse_compose_duration() {
+        assert_eq!(parse_compose_duration("10s"), Some(std::time::Duration::from_secs(10)));
+        assert_eq!(parse_compose_duration("1m30s"), Some(std::time::Duration::from_secs(90)));
+        assert!(parse_compose_duration("bogus").is_none());
+        assert!(parse_compose_duration("").is_none());
+    }
+
+    #[test]
+    fn test_validate_stack_semantics_collects_all_violations() {
+        let mut services = HashMap::new();
+        services.insert(
+            "web".to_string(),
+            Service::new("web")
+                .image("nginx:latest")
+                .depends_on_service("missing")
+                .volume("unregistered:/data")
+                .network("unregistered_net"),
+        );
+
+        let errors = validate_stack_semantics(&services, &[], &[]).unwrap_err();
+        assert!(errors.iter().any(|e| e.to_string().contains("no such service 'missing'")));
+        assert!(errors.iter().any(|e| e.to_string().contains("undeclared volume")));
+        assert!(errors.iter().any(|e| e.to_string().contains("undeclared network")));
+    }
+
+    #[test]
+    fn test_validate_stack_semantics_valid_stack() {
+        let mut services = HashMap::new();
+        services.insert("web".to_string(), Service::new("web").image("nginx:latest").volume("data:/data"));
+
+        assert!(validate_stack_semantics(&services, &[], &["data".to_string()]).is_ok());
+    }
+}
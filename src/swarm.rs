@@ -0,0 +1,111 @@
+use docker_api::opts::{SwarmInitOpts, SwarmJoinOpts, SwarmLeaveOpts};
+use docker_api::Swarm;
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pythonize::pythonize;
+
+use crate::error::DockerPyo3Error;
+use crate::{get_runtime, Pyo3Docker};
+
+#[pymodule]
+pub fn swarm(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Pyo3Swarm>()?;
+    Ok(())
+}
+
+/// Interface for initializing, joining, and inspecting a Docker Swarm.
+#[derive(Debug)]
+#[pyclass(name = "Swarm")]
+pub struct Pyo3Swarm(pub Swarm);
+
+#[pymethods]
+impl Pyo3Swarm {
+    #[new]
+    pub fn new(docker: Pyo3Docker) -> Self {
+        Pyo3Swarm(Swarm::new(docker.0))
+    }
+
+    /// Initialize this daemon as the first node of a new swarm.
+    ///
+    /// Returns:
+    ///     str: the new node's ID
+    #[pyo3(signature = (listen_addr=None, advertise_addr=None, force_new_cluster=None))]
+    pub fn init(
+        &self,
+        py: Python<'_>,
+        listen_addr: Option<&str>,
+        advertise_addr: Option<&str>,
+        force_new_cluster: Option<bool>,
+    ) -> PyResult<String> {
+        let mut opts = SwarmInitOpts::builder();
+        bo_setter!(listen_addr, opts);
+        bo_setter!(advertise_addr, opts);
+        bo_setter!(force_new_cluster, opts);
+
+        let rv = py.allow_threads(|| __swarm_init(&self.0, &opts.build()));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+
+    /// Join an existing swarm as a new node.
+    #[pyo3(signature = (remote_addrs, join_token, listen_addr=None, advertise_addr=None))]
+    pub fn join(
+        &self,
+        py: Python<'_>,
+        remote_addrs: &Bound<'_, PyList>,
+        join_token: &str,
+        listen_addr: Option<&str>,
+        advertise_addr: Option<&str>,
+    ) -> PyResult<()> {
+        let remote_addrs: Vec<String> = remote_addrs.extract().map_err(|_| {
+            DockerPyo3Error::InvalidParameter("remote_addrs must be a list of strings".to_string())
+        })?;
+        let remote_addrs: Vec<&str> = remote_addrs.iter().map(String::as_str).collect();
+
+        let mut opts = SwarmJoinOpts::builder();
+        opts = opts.remote_addrs(remote_addrs);
+        opts = opts.join_token(join_token);
+        bo_setter!(listen_addr, opts);
+        bo_setter!(advertise_addr, opts);
+
+        let rv = py.allow_threads(|| __swarm_join(&self.0, &opts.build()));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+
+    /// Leave the swarm this daemon currently belongs to.
+    #[pyo3(signature = (force=None))]
+    pub fn leave(&self, py: Python<'_>, force: Option<bool>) -> PyResult<()> {
+        let mut opts = SwarmLeaveOpts::builder();
+        bo_setter!(force, opts);
+
+        let rv = py.allow_threads(|| __swarm_leave(&self.0, &opts.build()));
+        rv.map_err(|e| DockerPyo3Error::from(e).into())
+    }
+
+    /// Inspect the current swarm.
+    ///
+    /// Returns:
+    ///     dict: swarm ID, version, join tokens, spec, etc.
+    pub fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __swarm_inspect(&self.0));
+        match rv {
+            Ok(rv) => Ok(pythonize_this!(rv)),
+            Err(e) => Err(DockerPyo3Error::from(e).into()),
+        }
+    }
+}
+
+fn __swarm_init(swarm: &Swarm, opts: &SwarmInitOpts) -> Result<String, docker_api::Error> {
+    get_runtime().block_on(swarm.init(opts))
+}
+
+fn __swarm_join(swarm: &Swarm, opts: &SwarmJoinOpts) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(swarm.join(opts))
+}
+
+fn __swarm_leave(swarm: &Swarm, opts: &SwarmLeaveOpts) -> Result<(), docker_api::Error> {
+    get_runtime().block_on(swarm.leave(opts))
+}
+
+fn __swarm_inspect(swarm: &Swarm) -> Result<docker_api::models::Swarm, docker_api::Error> {
+    get_runtime().block_on(swarm.inspect())
+}
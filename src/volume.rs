@@ -39,8 +39,8 @@ impl Pyo3Volumes {
         Pyo3Volume(self.0.get(name))
     }
 
-    pub fn prune(&self) -> PyResult<Py<PyAny>> {
-        let rv = __volumes_prune(&self.0, &Default::default());
+    pub fn prune(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __volumes_prune(&self.0, &Default::default()));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -48,8 +48,8 @@ impl Pyo3Volumes {
         }
     }
 
-    pub fn list(&self) -> PyResult<Py<PyAny>> {
-        let rv = __volumes_list(&self.0, &Default::default());
+    pub fn list(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __volumes_list(&self.0, &Default::default()));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -84,7 +84,7 @@ impl Pyo3Volumes {
         bo_setter!(driver_opts, opts);
         bo_setter!(labels, opts);
 
-        let rv = __volumes_create(&self.0, &opts.build());
+        let rv = py.allow_threads(|| __volumes_create(&self.0, &opts.build()));
 
         match rv {
             Ok(volume_response) => {
@@ -131,8 +131,8 @@ impl Pyo3Volume {
         self.0.name().to_string()
     }
 
-    pub fn inspect(&self) -> PyResult<Py<PyAny>> {
-        let rv = __volume_inspect(&self.0);
+    pub fn inspect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rv = py.allow_threads(|| __volume_inspect(&self.0));
 
         match rv {
             Ok(rv) => Ok(pythonize_this!(rv)),
@@ -140,8 +140,8 @@ impl Pyo3Volume {
         }
     }
 
-    pub fn delete(&self) -> PyResult<()> {
-        let rv = __volume_delete(&self.0);
+    pub fn delete(&self, py: Python<'_>) -> PyResult<()> {
+        let rv = py.allow_threads(|| __volume_delete(&self.0));
 
         match rv {
             Ok(rv) => Ok(rv),